@@ -1,6 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, contracterror, Address, Env, String, Symbol};
+use shared::access::{Access, ADMIN};
+use shared::fees::FeeManager;
+use soroban_sdk::{contract, contractimpl, contracttype, contracterror, vec, xdr::ToXdr, Address, BytesN, Env, IntoVal, String, Symbol, Vec};
 
 // Contract Errors
 #[contracterror]
@@ -20,6 +22,22 @@ pub enum ContractError {
     BadgeExpired = 11,
     RedemptionLimitReached = 12,
     TransactionAlreadyRedeemed = 13,
+    RedemptionTooSoon = 14,
+    ValidityTooShort = 15,
+    InvalidUpgrade = 16,
+    UriTooLong = 17,
+    HoldPeriodNotMet = 18,
+    RedemptionCooldownActive = 19,
+    BadgeNotExpired = 20,
+    InvalidMintFee = 21,
+    MintFeeFailed = 22,
+    NoActiveStake = 23,
+    LastAdmin = 24,
+    VoucherSignerNotSet = 25,
+    InvalidVoucher = 26,
+    VoucherAlreadyRedeemed = 27,
+    InvalidTransfer = 28,
+    HistoryLimitLocked = 29,
 }
 
 // Storage keys
@@ -33,6 +51,20 @@ pub enum DataKey {
     TotalBadgesMinted(u32),          // Counter per badge type
     PausedState,
     UsedTransactionHash(String),     // Track used transaction hashes globally
+    LastRedemption(Address),         // Last redemption timestamp per user (any badge)
+    MinUserRedemptionInterval,       // Minimum seconds between a user's redemptions
+    BadgeTypeIndex,                  // List of all created badge type IDs
+    MinValidityDuration,             // Floor for expiring badge type validity
+    UserTotalDiscount(Address),      // Cumulative discount_applied per user
+    MinHoldSeconds,                  // Minimum time a badge is held before redemption
+    TotalRedemptions(u32),           // Redemption counter per badge type
+    ActiveBadges(u32),               // Currently active badges per type
+    MintFee,                         // Fee charged to badge recipients on mint
+    StakePool,                       // Pool a user must be staked in to redeem
+    AdminCount,                      // Number of accounts holding the admin role
+    VoucherSigner,                   // Ed25519 key that signs discount vouchers
+    UsedVoucher(u64),                // Track redeemed voucher ids
+    HistoryLocked(u32),              // Set once a badge type has been held; fixes its history limit
 }
 
 // Badge struct
@@ -56,9 +88,23 @@ pub struct BadgeMetadata {
     pub discount_bps: u32,
     pub max_redemptions: u32,
     pub validity_duration: u64, // Duration in seconds
+    pub uri: String,            // Off-chain art/description, at most MAX_URI_LEN bytes
+    pub redemption_cooldown: u64, // Seconds between a user's redemptions of this type (0 = none)
+    pub history_limit: u32,     // Redemption records kept per user, oldest overwritten (0 = all)
     pub enabled: bool,
 }
 
+// Caller-supplied metadata for create_badge_type
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BadgeTypeConfig {
+    pub name: String,
+    pub discount_bps: u32,
+    pub max_redemptions: u32,
+    pub validity_duration: u64, // Duration in seconds (0 = never expires)
+    pub uri: String,            // Off-chain art/description, at most MAX_URI_LEN bytes
+}
+
 // Redemption record for audit trail
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -69,6 +115,46 @@ pub struct RedemptionRecord {
     pub transaction_hash: String,
 }
 
+// Aggregate counters for a badge type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeStats {
+    pub minted: u32,
+    pub active: u32,            // Minted and not revoked, replaced, or upgraded away
+    pub total_redemptions: u32,
+}
+
+// Fee charged to the recipient of each minted badge
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintFeeConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address,
+}
+
+// Off-chain issued one-time discount, signed by the voucher signer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voucher {
+    pub id: u64,
+    pub user: Address,
+    pub discount_bps: u32,
+    pub expires_at: u64, // Expiry timestamp (0 = never expires)
+}
+
+// Outcome of a successful redemption
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedemptionResult {
+    pub discount_bps: u32,
+    pub redemptions_remaining: u32, // u32::MAX when redemptions are unlimited
+    pub badge_exhausted: bool,      // True if this was the final allowed redemption
+}
+
+// Maximum length in bytes of a badge type metadata URI
+pub const MAX_URI_LEN: u32 = 256;
+
 #[contract]
 pub struct AcademyRewardsContract;
 
@@ -85,37 +171,109 @@ impl AcademyRewardsContract {
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::PausedState, &false);
+        Access::init(&env, &admin);
+        env.storage().instance().set(&DataKey::AdminCount, &1u32);
         
         Ok(())
     }
 
     // ========== ADMIN FUNCTIONS ==========
 
+    /// Give `new_admin` the same rights as every other admin
+    pub fn add_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+
+        if !Access::has_role(&env, ADMIN, &new_admin) {
+            Access::set_role(&env, ADMIN, &new_admin);
+            let count = Self::admin_count(&env);
+            env.storage().instance().set(&DataKey::AdminCount, &(count + 1));
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_added"),), (admin, new_admin));
+
+        Ok(())
+    }
+
+    /// Remove an admin; the last remaining admin cannot be removed
+    pub fn remove_admin(env: Env, admin: Address, account: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+
+        if Access::has_role(&env, ADMIN, &account) {
+            let count = Self::admin_count(&env);
+            if count <= 1 {
+                return Err(ContractError::LastAdmin);
+            }
+            Access::revoke_role(&env, ADMIN, &account);
+            env.storage().instance().set(&DataKey::AdminCount, &(count - 1));
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_removed"),), (admin, account));
+
+        Ok(())
+    }
+
     /// Create a new badge type
     pub fn create_badge_type(
         env: Env,
         admin: Address,
         badge_type: u32,
-        name: String,
-        discount_bps: u32,
-        max_redemptions: u32,
-        validity_duration: u64,
+        config: BadgeTypeConfig,
     ) -> Result<(), ContractError> {
         Self::require_admin(&env, &admin)?;
+        let BadgeTypeConfig { name, discount_bps, max_redemptions, validity_duration, uri } = config;
+
+        if uri.len() > MAX_URI_LEN {
+            return Err(ContractError::UriTooLong);
+        }
 
         // Validate discount (max 100% = 10000 bps)
         if discount_bps > 10000 {
             return Err(ContractError::InvalidDiscount);
         }
 
+        // Expiring badge types must last at least the configured floor
+        if validity_duration > 0 && validity_duration < Self::get_min_validity_duration(env.clone()) {
+            return Err(ContractError::ValidityTooShort);
+        }
+
+        // Re-creating a type keeps its cooldown and history limit; holders' history slots depend on the limit
+        let (redemption_cooldown, history_limit) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BadgeMetadata>(&DataKey::BadgeMetadata(badge_type))
+            .map(|existing| (existing.redemption_cooldown, existing.history_limit))
+            .unwrap_or((0, 0));
+
         let metadata = BadgeMetadata {
             name,
             discount_bps,
             max_redemptions,
             validity_duration,
+            uri,
+            redemption_cooldown,
+            history_limit,
             enabled: true,
         };
 
+        // Index newly created badge types for enumeration
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::BadgeMetadata(badge_type))
+        {
+            let mut index: Vec<u32> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::BadgeTypeIndex)
+                .unwrap_or_else(|| Vec::new(&env));
+            index.push_back(badge_type);
+            env.storage()
+                .persistent()
+                .set(&DataKey::BadgeTypeIndex, &index);
+        }
+
         env.storage()
             .persistent()
             .set(&DataKey::BadgeMetadata(badge_type), &metadata);
@@ -156,6 +314,19 @@ impl AcademyRewardsContract {
             if existing.badge_type == badge_type && existing.active {
                 return Err(ContractError::UserAlreadyHasBadge);
             }
+            // The new badge replaces the existing one
+            if existing.active {
+                Self::adjust_active_badges(&env, existing.badge_type, false);
+            }
+        }
+
+        // Charge the recipient the mint fee, if one is configured
+        if let Some(fee) = Self::get_mint_fee(env.clone()) {
+            if fee.amount > 0 {
+                recipient.require_auth();
+                FeeManager::collect_fee(&env, &fee.token, &recipient, &fee.treasury, fee.amount)
+                    .map_err(|_| ContractError::MintFeeFailed)?;
+            }
         }
 
         // Create badge
@@ -188,10 +359,14 @@ impl AcademyRewardsContract {
         env.storage()
             .persistent()
             .set(&DataKey::TotalBadgesMinted(badge_type), &count);
+        Self::adjust_active_badges(&env, badge_type, true);
+        env.storage()
+            .persistent()
+            .set(&DataKey::HistoryLocked(badge_type), &true);
 
         // Emit event
         env.events().publish(
-            (Symbol::new(&env, "badge_minted"),),
+            (Symbol::new(&env, "badge_minted"), badge_type),
             (recipient, badge_type, env.ledger().timestamp()),
         );
         
@@ -205,11 +380,14 @@ impl AcademyRewardsContract {
         let badge_key = DataKey::Badge(user.clone());
 
         if let Some(mut badge) = env.storage().persistent().get::<DataKey, Badge>(&badge_key) {
+            if badge.active {
+                Self::adjust_active_badges(&env, badge.badge_type, false);
+            }
             badge.active = false;
             env.storage().persistent().set(&badge_key, &badge);
 
             env.events().publish(
-                (Symbol::new(&env, "badge_revoked"),),
+                (Symbol::new(&env, "badge_revoked"), badge.badge_type),
                 (user, badge.badge_type),
             );
             
@@ -219,81 +397,459 @@ impl AcademyRewardsContract {
         }
     }
 
-    /// Pause/unpause contract
-    pub fn set_paused(env: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
+    /// Delete a user's expired badge to reclaim its storage
+    pub fn sweep_expired_badge(env: Env, admin: Address, user: Address) -> Result<(), ContractError> {
         Self::require_admin(&env, &admin)?;
-        env.storage().instance().set(&DataKey::PausedState, &paused);
+
+        let badge_key = DataKey::Badge(user.clone());
+        let badge: Badge = env
+            .storage()
+            .persistent()
+            .get(&badge_key)
+            .ok_or(ContractError::UserHasNoBadge)?;
+
+        if !Self::badge_expired(&env, &badge) {
+            return Err(ContractError::BadgeNotExpired);
+        }
+
+        if badge.active {
+            Self::adjust_active_badges(&env, badge.badge_type, false);
+        }
+        env.storage().persistent().remove(&badge_key);
+
+        env.events().publish(
+            (Symbol::new(&env, "badge_expired"), badge.badge_type),
+            (user, badge.badge_type),
+        );
+
         Ok(())
     }
 
-    // ========== USER FUNCTIONS ==========
-
-    /// Redeem badge for fee discount
-    /// Returns the discount amount in basis points
-    pub fn redeem_badge(
+    /// Promote a user's active badge to a higher tier, keeping its redemption count
+    pub fn upgrade_badge(
         env: Env,
+        admin: Address,
         user: Address,
-        transaction_hash: String,
-    ) -> Result<u32, ContractError> {
-        user.require_auth();
-        Self::require_not_paused(&env)?;
+        new_badge_type: u32,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
 
-        // Check if transaction hash has been used before (globally)
-        let tx_key = DataKey::UsedTransactionHash(transaction_hash.clone());
-        if env.storage().persistent().has(&tx_key) {
-            return Err(ContractError::TransactionAlreadyRedeemed);
+        let badge_key = DataKey::Badge(user.clone());
+        let mut badge: Badge = env
+            .storage()
+            .persistent()
+            .get(&badge_key)
+            .ok_or(ContractError::UserHasNoBadge)?;
+
+        if !badge.active {
+            return Err(ContractError::BadgeNotActive);
         }
 
-        let badge_key = DataKey::Badge(user.clone());
+        if badge.expiry > 0 && env.ledger().timestamp() > badge.expiry {
+            return Err(ContractError::BadgeExpired);
+        }
+
+        // Higher badge type IDs are higher tiers
+        if new_badge_type <= badge.badge_type {
+            return Err(ContractError::InvalidUpgrade);
+        }
 
+        let metadata: BadgeMetadata = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BadgeMetadata(new_badge_type))
+            .ok_or(ContractError::BadgeTypeNotFound)?;
+
+        if !metadata.enabled {
+            return Err(ContractError::BadgeTypeDisabled);
+        }
+
+        let old_badge_type = badge.badge_type;
+        Self::adjust_active_badges(&env, old_badge_type, false);
+        Self::adjust_active_badges(&env, new_badge_type, true);
+        env.storage()
+            .persistent()
+            .set(&DataKey::HistoryLocked(new_badge_type), &true);
+
+        // Re-slot the history for the new type's ring buffer size
+        Self::migrate_history(
+            &env,
+            &user,
+            badge.redeemed_count,
+            Self::history_limit(&env, old_badge_type),
+            metadata.history_limit,
+        );
+
+        badge.badge_type = new_badge_type;
+        badge.discount_bps = metadata.discount_bps;
+        badge.max_redemptions = metadata.max_redemptions;
+        badge.expiry = if metadata.validity_duration > 0 {
+            env.ledger().timestamp() + metadata.validity_duration
+        } else {
+            0
+        };
+
+        env.storage().persistent().set(&badge_key, &badge);
+
+        env.events().publish(
+            (Symbol::new(&env, "badge_upgraded"),),
+            (user, old_badge_type, new_badge_type),
+        );
+
+        Ok(())
+    }
+
+    /// Move a user's badge, redemption history and discount total to a new address (admin only)
+    ///
+    /// The destination must not hold any badge, active or not.
+    pub fn transfer_badge(
+        env: Env,
+        admin: Address,
+        from: Address,
+        to: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+
+        if from == to {
+            return Err(ContractError::InvalidTransfer);
+        }
+
+        let from_key = DataKey::Badge(from.clone());
+        let badge: Badge = env
+            .storage()
+            .persistent()
+            .get(&from_key)
+            .ok_or(ContractError::UserHasNoBadge)?;
+
+        // Never overwrite a destination badge (active or not) or leave its history behind
+        let to_key = DataKey::Badge(to.clone());
+        if env.storage().persistent().has(&to_key) {
+            return Err(ContractError::UserAlreadyHasBadge);
+        }
+
+        // Move redemption history so indices keep lining up with redeemed_count
+        let history_limit = Self::history_limit(&env, badge.badge_type);
+        let stored_records = if history_limit > 0 {
+            badge.redeemed_count.min(history_limit)
+        } else {
+            badge.redeemed_count
+        };
+        for index in 0..stored_records {
+            let old_key = DataKey::RedemptionHistory(from.clone(), index);
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, RedemptionRecord>(&old_key)
+            {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::RedemptionHistory(to.clone(), index), &record);
+                env.storage().persistent().remove(&old_key);
+            }
+        }
+
+        // Carry over the redemption interval anchor
+        let last_key = DataKey::LastRedemption(from.clone());
+        if let Some(last) = env.storage().persistent().get::<DataKey, u64>(&last_key) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::LastRedemption(to.clone()), &last);
+            env.storage().persistent().remove(&last_key);
+        }
+
+        // Carry over the cumulative discount total
+        let from_total_key = DataKey::UserTotalDiscount(from.clone());
+        if let Some(total) = env.storage().persistent().get::<DataKey, u64>(&from_total_key) {
+            let to_total_key = DataKey::UserTotalDiscount(to.clone());
+            let existing: u64 = env.storage().persistent().get(&to_total_key).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&to_total_key, &(existing + total));
+            env.storage().persistent().remove(&from_total_key);
+        }
+
+        env.storage().persistent().set(&to_key, &badge);
+        env.storage().persistent().remove(&from_key);
+
+        env.events().publish(
+            (Symbol::new(&env, "badge_transferred"),),
+            (from, to, badge.badge_type),
+        );
+
+        Ok(())
+    }
+
+    /// Push out the expiry of a user's live badge (badges that never expire are left unchanged)
+    pub fn extend_badge(
+        env: Env,
+        admin: Address,
+        user: Address,
+        additional_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+
+        let badge_key = DataKey::Badge(user.clone());
         let mut badge: Badge = env
             .storage()
             .persistent()
             .get(&badge_key)
             .ok_or(ContractError::UserHasNoBadge)?;
 
-        // Validation checks
         if !badge.active {
             return Err(ContractError::BadgeNotActive);
         }
 
-        // Check expiry
         if badge.expiry > 0 && env.ledger().timestamp() > badge.expiry {
             return Err(ContractError::BadgeExpired);
         }
 
-        // Check redemption limit
-        if badge.max_redemptions > 0 && badge.redeemed_count >= badge.max_redemptions {
-            return Err(ContractError::RedemptionLimitReached);
+        if badge.expiry > 0 {
+            badge.expiry = badge.expiry.saturating_add(additional_seconds);
+            env.storage().persistent().set(&badge_key, &badge);
         }
 
-        // Record redemption
-        let redemption_record = RedemptionRecord {
-            badge_type: badge.badge_type,
-            timestamp: env.ledger().timestamp(),
-            discount_applied: badge.discount_bps,
-            transaction_hash: transaction_hash.clone(),
-        };
+        env.events().publish(
+            (Symbol::new(&env, "badge_extended"),),
+            (user, badge.badge_type, badge.expiry),
+        );
+
+        Ok(())
+    }
 
-        // Store redemption at the current count index BEFORE incrementing
-        let redemption_key = DataKey::RedemptionHistory(user.clone(), badge.redeemed_count);
+    /// Pause/unpause contract
+    pub fn set_paused(env: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::PausedState, &paused);
+        Ok(())
+    }
+
+    /// Set the minimum interval between any two redemptions by the same user (0 = disabled)
+    pub fn set_min_user_redemption_interval(
+        env: Env,
+        admin: Address,
+        interval: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
         env.storage()
+            .instance()
+            .set(&DataKey::MinUserRedemptionInterval, &interval);
+        Ok(())
+    }
+
+    /// Set the minimum time between a user's redemptions of a badge type (0 = disabled)
+    pub fn set_redemption_cooldown(
+        env: Env,
+        admin: Address,
+        badge_type: u32,
+        cooldown: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = DataKey::BadgeMetadata(badge_type);
+        let mut metadata: BadgeMetadata = env
+            .storage()
             .persistent()
-            .set(&redemption_key, &redemption_record);
+            .get(&key)
+            .ok_or(ContractError::BadgeTypeNotFound)?;
+        metadata.redemption_cooldown = cooldown;
+        env.storage().persistent().set(&key, &metadata);
+        Ok(())
+    }
 
-        // Mark transaction as used globally
-        env.storage().persistent().set(&tx_key, &true);
+    /// Keep only the last `history_limit` redemption records per user for a badge type (0 = keep all)
+    pub fn set_history_limit(
+        env: Env,
+        admin: Address,
+        badge_type: u32,
+        history_limit: u32,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
 
-        // Update badge (increment count)
-        badge.redeemed_count += 1;
-        env.storage().persistent().set(&badge_key, &badge);
+        let key = DataKey::BadgeMetadata(badge_type);
+        let mut metadata: BadgeMetadata = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::BadgeTypeNotFound)?;
+
+        // Existing holders' history slots are derived from the limit, so it is fixed once the type is held
+        if metadata.history_limit != history_limit
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::HistoryLocked(badge_type))
+        {
+            return Err(ContractError::HistoryLimitLocked);
+        }
+        metadata.history_limit = history_limit;
+        env.storage().persistent().set(&key, &metadata);
+        Ok(())
+    }
+
+    /// Set how long a badge must be held after it was earned before it can be redeemed (0 = disabled)
+    pub fn set_min_hold_seconds(
+        env: Env,
+        admin: Address,
+        min_hold_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::MinHoldSeconds, &min_hold_seconds);
+        Ok(())
+    }
+
+    /// Charge badge recipients `amount` of `token` on mint, paid to `treasury` (0 = free)
+    pub fn set_mint_fee(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        treasury: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        if amount < 0 {
+            return Err(ContractError::InvalidMintFee);
+        }
+        env.storage().instance().set(
+            &DataKey::MintFee,
+            &MintFeeConfig {
+                token,
+                amount,
+                treasury,
+            },
+        );
+        Ok(())
+    }
+
+    /// Set the ed25519 public key that signs discount vouchers
+    pub fn set_voucher_signer(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::VoucherSigner, &public_key);
+        Ok(())
+    }
+
+    /// Get the voucher signing key, if one is set
+    pub fn get_voucher_signer(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::VoucherSigner)
+    }
+
+    /// Only let users with a stake in `pool` redeem badges (None = no requirement)
+    pub fn set_stake_requirement(
+        env: Env,
+        admin: Address,
+        pool: Option<Address>,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        match pool {
+            Some(pool) => env.storage().instance().set(&DataKey::StakePool, &pool),
+            None => env.storage().instance().remove(&DataKey::StakePool),
+        }
+        Ok(())
+    }
+
+    /// Set the minimum validity for expiring badge types (never-expiring types are exempt)
+    pub fn set_min_validity_duration(
+        env: Env,
+        admin: Address,
+        min_duration: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::MinValidityDuration, &min_duration);
+        Ok(())
+    }
+
+    // ========== USER FUNCTIONS ==========
+
+    /// Redeem badge for fee discount
+    /// Returns the discount in basis points along with the remaining redemptions
+    pub fn redeem_badge(
+        env: Env,
+        user: Address,
+        transaction_hash: String,
+    ) -> Result<RedemptionResult, ContractError> {
+        user.require_auth();
+        let badge = Self::check_redemption(&env, &user, &transaction_hash)?;
+        Ok(Self::record_redemption(&env, user, badge, transaction_hash, "badge_redeemed"))
+    }
+
+    /// Force a redemption for a support case, skipping only the cooldown, interval and limit checks.
+    /// The badge must still be active, unexpired, held long enough and staked for, and the
+    /// transaction hash is still deduplicated.
+    pub fn admin_redeem(
+        env: Env,
+        admin: Address,
+        user: Address,
+        transaction_hash: String,
+    ) -> Result<RedemptionResult, ContractError> {
+        Self::require_admin(&env, &admin)?;
+        let badge = Self::check_badge_redeemable(&env, &user, &transaction_hash)?;
+
+        Ok(Self::record_redemption(&env, user, badge, transaction_hash, "admin_redeemed"))
+    }
+
+    /// Redeem a one-time voucher signed off-chain by the voucher signer; no badge is needed.
+    /// The signature covers the XDR encoding of `(contract_address, voucher)`, so a voucher only
+    /// redeems on the deployment it was issued for, and each voucher id redeems once.
+    ///
+    /// A bad signature does not return `InvalidVoucher`: signature verification traps, and the
+    /// call fails with the host error `Error(Crypto, InvalidInput)`.
+    pub fn redeem_voucher(
+        env: Env,
+        user: Address,
+        voucher: Voucher,
+        signature: BytesN<64>,
+    ) -> Result<u32, ContractError> {
+        user.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let signer: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::VoucherSigner)
+            .ok_or(ContractError::VoucherSignerNotSet)?;
+
+        if voucher.user != user || voucher.discount_bps > 10000 {
+            return Err(ContractError::InvalidVoucher);
+        }
+        if voucher.expires_at > 0 && env.ledger().timestamp() > voucher.expires_at {
+            return Err(ContractError::InvalidVoucher);
+        }
+
+        let used_key = DataKey::UsedVoucher(voucher.id);
+        if env.storage().persistent().has(&used_key) {
+            return Err(ContractError::VoucherAlreadyRedeemed);
+        }
+
+        // Traps on a bad signature
+        let payload = (env.current_contract_address(), voucher.clone()).to_xdr(&env);
+        env.crypto().ed25519_verify(&signer, &payload, &signature);
+
+        env.storage().persistent().set(&used_key, &true);
+
+        let total_key = DataKey::UserTotalDiscount(user.clone());
+        let total: u64 = env.storage().persistent().get(&total_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&total_key, &(total + voucher.discount_bps as u64));
 
-        // Emit event
         env.events().publish(
-            (Symbol::new(&env, "badge_redeemed"),),
-            (user, badge.badge_type, badge.discount_bps),
+            (Symbol::new(&env, "voucher_redeemed"), voucher.id),
+            (user, voucher.discount_bps),
         );
 
+        Ok(voucher.discount_bps)
+    }
+
+    /// Dry-run `redeem_badge`: the discount it would apply, or the error it would return
+    pub fn can_redeem(env: Env, user: Address, transaction_hash: String) -> Result<u32, ContractError> {
+        let badge = Self::check_redemption(&env, &user, &transaction_hash)?;
         Ok(badge.discount_bps)
     }
 
@@ -321,6 +877,23 @@ impl AcademyRewardsContract {
         0
     }
 
+    /// Check whether a user's badge has passed its expiry
+    pub fn is_badge_expired(env: Env, user: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<DataKey, Badge>(&DataKey::Badge(user))
+            .map(|badge| Self::badge_expired(&env, &badge))
+            .unwrap_or(false)
+    }
+
+    /// Get the cumulative discount (bps) a user has redeemed across all badges
+    pub fn get_user_total_discount(env: Env, user: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::UserTotalDiscount(user))
+            .unwrap_or(0)
+    }
+
     /// Get user's badge information
     pub fn get_user_badge(env: Env, user: Address) -> Option<Badge> {
         env.storage().persistent().get(&DataKey::Badge(user))
@@ -333,6 +906,25 @@ impl AcademyRewardsContract {
             .get(&DataKey::BadgeMetadata(badge_type))
     }
 
+    /// Get all created badge type IDs
+    pub fn get_badge_types(env: Env) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BadgeTypeIndex)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get metadata for every created badge type
+    pub fn get_all_badge_metadata(env: Env) -> Vec<(u32, BadgeMetadata)> {
+        let mut all = Vec::new(&env);
+        for badge_type in Self::get_badge_types(env.clone()).iter() {
+            if let Some(metadata) = Self::get_badge_metadata(env.clone(), badge_type) {
+                all.push_back((badge_type, metadata));
+            }
+        }
+        all
+    }
+
     /// Get total badges minted for a type
     pub fn get_total_minted(env: Env, badge_type: u32) -> u32 {
         env.storage()
@@ -341,11 +933,93 @@ impl AcademyRewardsContract {
             .unwrap_or(0)
     }
 
-    /// Get redemption history for user
+    /// Get minted, active and redeemed counts for a badge type
+    pub fn get_badge_stats(env: Env, badge_type: u32) -> BadgeStats {
+        BadgeStats {
+            minted: Self::get_total_minted(env.clone(), badge_type),
+            active: env
+                .storage()
+                .persistent()
+                .get(&DataKey::ActiveBadges(badge_type))
+                .unwrap_or(0),
+            total_redemptions: env
+                .storage()
+                .persistent()
+                .get(&DataKey::TotalRedemptions(badge_type))
+                .unwrap_or(0),
+        }
+    }
+
+    /// Get the minimum interval between a user's redemptions
+    pub fn get_min_user_redemption_interval(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinUserRedemptionInterval)
+            .unwrap_or(0)
+    }
+
+    /// Get the minimum time a badge must be held before redemption
+    pub fn get_min_hold_seconds(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinHoldSeconds)
+            .unwrap_or(0)
+    }
+
+    /// Get the minimum validity for expiring badge types
+    pub fn get_min_validity_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinValidityDuration)
+            .unwrap_or(0)
+    }
+
+    /// Check whether `account` is one of the admins
+    pub fn is_admin(env: Env, account: Address) -> bool {
+        Access::has_role(&env, ADMIN, &account)
+    }
+
+    /// Get the pool users must be staked in to redeem, if any
+    pub fn get_stake_requirement(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::StakePool)
+    }
+
+    /// Get the mint fee configuration, if one was set
+    pub fn get_mint_fee(env: Env) -> Option<MintFeeConfig> {
+        env.storage().instance().get(&DataKey::MintFee)
+    }
+
+    /// Get the number of times the user has redeemed their current badge
+    pub fn get_redemption_count(env: Env, user: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, Badge>(&DataKey::Badge(user))
+            .map(|badge| badge.redeemed_count)
+            .unwrap_or(0)
+    }
+
+    /// Get redemption history for user by redemption index (None if it was overwritten)
     pub fn get_redemption_history(env: Env, user: Address, index: u32) -> Option<RedemptionRecord> {
+        let mut slot = index;
+        if let Some(badge) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Badge>(&DataKey::Badge(user.clone()))
+        {
+            let history_limit = Self::history_limit(&env, badge.badge_type);
+            if history_limit > 0 {
+                if index >= badge.redeemed_count
+                    || index < badge.redeemed_count.saturating_sub(history_limit)
+                {
+                    return None;
+                }
+                slot = index % history_limit;
+            }
+        }
+
         env.storage()
             .persistent()
-            .get(&DataKey::RedemptionHistory(user, index))
+            .get(&DataKey::RedemptionHistory(user, slot))
     }
 
     // ========== HELPER FUNCTIONS ==========
@@ -353,19 +1027,263 @@ impl AcademyRewardsContract {
     fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
         admin.require_auth();
 
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(ContractError::NotInitialized)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(ContractError::NotInitialized);
+        }
 
-        if admin != &stored_admin {
+        if !Access::has_role(env, ADMIN, admin) {
             return Err(ContractError::Unauthorized);
         }
         
         Ok(())
     }
 
+    fn admin_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AdminCount)
+            .unwrap_or(0)
+    }
+
+    /// Run every redemption check without requiring auth or changing state
+    fn check_redemption(
+        env: &Env,
+        user: &Address,
+        transaction_hash: &String,
+    ) -> Result<Badge, ContractError> {
+        let badge = Self::check_badge_redeemable(env, user, transaction_hash)?;
+
+        // Check redemption limit
+        if badge.max_redemptions > 0 && badge.redeemed_count >= badge.max_redemptions {
+            return Err(ContractError::RedemptionLimitReached);
+        }
+
+        // Check per-user interval across all badges
+        let last_key = DataKey::LastRedemption(user.clone());
+        let min_interval: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinUserRedemptionInterval)
+            .unwrap_or(0);
+        if min_interval > 0 {
+            if let Some(last) = env.storage().persistent().get::<DataKey, u64>(&last_key) {
+                if env.ledger().timestamp().saturating_sub(last) < min_interval {
+                    return Err(ContractError::RedemptionTooSoon);
+                }
+            }
+        }
+
+        // Check the badge type's own cooldown
+        let cooldown = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BadgeMetadata>(&DataKey::BadgeMetadata(badge.badge_type))
+            .map(|metadata| metadata.redemption_cooldown)
+            .unwrap_or(0);
+        if cooldown > 0 {
+            if let Some(last) = env.storage().persistent().get::<DataKey, u64>(&last_key) {
+                if env.ledger().timestamp().saturating_sub(last) < cooldown {
+                    return Err(ContractError::RedemptionCooldownActive);
+                }
+            }
+        }
+
+        Ok(badge)
+    }
+
+    /// Checks every redemption must pass, admin_redeem included: the hash is unused and the
+    /// badge is active, unexpired, held long enough and backed by a stake if one is required
+    fn check_badge_redeemable(
+        env: &Env,
+        user: &Address,
+        transaction_hash: &String,
+    ) -> Result<Badge, ContractError> {
+        Self::require_not_paused(env)?;
+
+        // Check if transaction hash has been used before (globally)
+        let tx_key = DataKey::UsedTransactionHash(transaction_hash.clone());
+        if env.storage().persistent().has(&tx_key) {
+            return Err(ContractError::TransactionAlreadyRedeemed);
+        }
+
+        let badge_key = DataKey::Badge(user.clone());
+
+        let badge: Badge = env
+            .storage()
+            .persistent()
+            .get(&badge_key)
+            .ok_or(ContractError::UserHasNoBadge)?;
+
+        // Validation checks
+        if !badge.active {
+            return Err(ContractError::BadgeNotActive);
+        }
+
+        // Check expiry
+        if badge.expiry > 0 && env.ledger().timestamp() > badge.expiry {
+            return Err(ContractError::BadgeExpired);
+        }
+
+        // Check the badge has been held long enough to deter fresh sybil accounts
+        let min_hold = Self::get_min_hold_seconds(env.clone());
+        if min_hold > 0 && env.ledger().timestamp() < badge.earned_at.saturating_add(min_hold) {
+            return Err(ContractError::HoldPeriodNotMet);
+        }
+
+        // Check the user is staked in the configured pool
+        if let Some(pool) = Self::get_stake_requirement(env.clone()) {
+            let stake: i128 = env.invoke_contract(
+                &pool,
+                &Symbol::new(env, "get_user_stake"),
+                vec![env, user.into_val(env)],
+            );
+            if stake <= 0 {
+                return Err(ContractError::NoActiveStake);
+            }
+        }
+
+        Ok(badge)
+    }
+
+    fn record_redemption(
+        env: &Env,
+        user: Address,
+        mut badge: Badge,
+        transaction_hash: String,
+        event: &str,
+    ) -> RedemptionResult {
+        let tx_key = DataKey::UsedTransactionHash(transaction_hash.clone());
+        let badge_key = DataKey::Badge(user.clone());
+        let last_key = DataKey::LastRedemption(user.clone());
+
+        // Record redemption
+        let redemption_record = RedemptionRecord {
+            badge_type: badge.badge_type,
+            timestamp: env.ledger().timestamp(),
+            discount_applied: badge.discount_bps,
+            transaction_hash: transaction_hash.clone(),
+        };
+
+        // Store redemption at the current count index BEFORE incrementing,
+        // wrapping around to overwrite the oldest record when history is limited
+        let history_limit = Self::history_limit(env, badge.badge_type);
+        let slot = Self::history_slot(badge.redeemed_count, history_limit);
+        let redemption_key = DataKey::RedemptionHistory(user.clone(), slot);
+        env.storage()
+            .persistent()
+            .set(&redemption_key, &redemption_record);
+
+        // Mark transaction as used globally
+        env.storage().persistent().set(&tx_key, &true);
+
+        // Track the user's latest redemption time
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
+
+        // Accumulate the user's total redeemed discount
+        let total_key = DataKey::UserTotalDiscount(user.clone());
+        let total: u64 = env.storage().persistent().get(&total_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&total_key, &(total + badge.discount_bps as u64));
+
+        // Count redemptions per badge type for reporting
+        let redemptions_key = DataKey::TotalRedemptions(badge.badge_type);
+        let redemptions: u32 = env.storage().persistent().get(&redemptions_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&redemptions_key, &(redemptions + 1));
+
+        // Update badge (increment count)
+        badge.redeemed_count += 1;
+        env.storage().persistent().set(&badge_key, &badge);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, event), badge.badge_type),
+            (user, badge.badge_type, badge.discount_bps),
+        );
+
+        let redemptions_remaining = if badge.max_redemptions == 0 {
+            u32::MAX
+        } else {
+            badge.max_redemptions.saturating_sub(badge.redeemed_count)
+        };
+
+        RedemptionResult {
+            discount_bps: badge.discount_bps,
+            redemptions_remaining,
+            badge_exhausted: badge.max_redemptions > 0 && redemptions_remaining == 0,
+        }
+    }
+
+    fn history_slot(index: u32, history_limit: u32) -> u32 {
+        if history_limit > 0 {
+            index % history_limit
+        } else {
+            index
+        }
+    }
+
+    /// Move a user's stored redemption records from one ring buffer size to another
+    fn migrate_history(env: &Env, user: &Address, redeemed_count: u32, old_limit: u32, new_limit: u32) {
+        if old_limit == new_limit {
+            return;
+        }
+
+        let first = if old_limit > 0 {
+            redeemed_count.saturating_sub(old_limit)
+        } else {
+            0
+        };
+        let mut records: Vec<(u32, RedemptionRecord)> = Vec::new(env);
+        for index in first..redeemed_count {
+            let key = DataKey::RedemptionHistory(user.clone(), Self::history_slot(index, old_limit));
+            if let Some(record) = env.storage().persistent().get::<DataKey, RedemptionRecord>(&key) {
+                records.push_back((index, record));
+                env.storage().persistent().remove(&key);
+            }
+        }
+
+        let keep_from = if new_limit > 0 {
+            redeemed_count.saturating_sub(new_limit)
+        } else {
+            0
+        };
+        for (index, record) in records.iter() {
+            if index >= keep_from {
+                env.storage().persistent().set(
+                    &DataKey::RedemptionHistory(user.clone(), Self::history_slot(index, new_limit)),
+                    &record,
+                );
+            }
+        }
+    }
+
+    fn history_limit(env: &Env, badge_type: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, BadgeMetadata>(&DataKey::BadgeMetadata(badge_type))
+            .map(|metadata| metadata.history_limit)
+            .unwrap_or(0)
+    }
+
+    fn badge_expired(env: &Env, badge: &Badge) -> bool {
+        badge.expiry > 0 && env.ledger().timestamp() > badge.expiry
+    }
+
+    fn adjust_active_badges(env: &Env, badge_type: u32, increase: bool) {
+        let key = DataKey::ActiveBadges(badge_type);
+        let active: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let active = if increase {
+            active + 1
+        } else {
+            active.saturating_sub(1)
+        };
+        env.storage().persistent().set(&key, &active);
+    }
+
     fn require_not_paused(env: &Env) -> Result<(), ContractError> {
         let paused: bool = env
             .storage()
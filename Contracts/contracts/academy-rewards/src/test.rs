@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod test {
-    use crate::{AcademyRewardsContract, AcademyRewardsContractClient, Badge, BadgeMetadata, ContractError, DataKey};
-    use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+    use crate::{AcademyRewardsContract, AcademyRewardsContractClient, Badge, BadgeMetadata, BadgeTypeConfig, BadgeStats, ContractError, DataKey, Voucher};
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, testutils::Events as _, testutils::Ledger as _, token, xdr::ToXdr, Address, BytesN, Env, String, Symbol, TryIntoVal};
 
     fn setup_env() -> (Env, Address, Address, Address) {
         let env = Env::default();
@@ -16,6 +17,16 @@ mod test {
         (env, admin, user, contract_id)
     }
 
+    fn badge_config(env: &Env, name: &str, discount_bps: u32, max_redemptions: u32, validity_duration: u64) -> BadgeTypeConfig {
+        BadgeTypeConfig {
+            name: String::from_str(env, name),
+            discount_bps,
+            max_redemptions,
+            validity_duration,
+            uri: String::from_str(env, ""),
+        }
+    }
+
     #[test]
     fn test_initialize_and_double_init() {
         let (env, admin, _user, contract_id) = setup_env();
@@ -36,24 +47,10 @@ mod test {
 
         client.initialize(&admin);
 
-        let unauthorized = client.try_create_badge_type(
-            &user,
-            &1,
-            &String::from_str(&_env, "Bronze"),
-            &500,
-            &10,
-            &0,
-        );
+        let unauthorized = client.try_create_badge_type(&user, &1, &badge_config(&_env, "Bronze", 500, 10, 0));
         assert_eq!(unauthorized, Err(Ok(ContractError::Unauthorized)));
 
-        let invalid_discount = client.try_create_badge_type(
-            &admin,
-            &1,
-            &String::from_str(&_env, "Bronze"),
-            &10001,
-            &10,
-            &0,
-        );
+        let invalid_discount = client.try_create_badge_type(&admin, &1, &badge_config(&_env, "Bronze", 10001, 10, 0));
         assert_eq!(invalid_discount, Err(Ok(ContractError::InvalidDiscount)));
     }
 
@@ -73,14 +70,7 @@ mod test {
         assert_eq!(missing_type, Err(Ok(ContractError::BadgeTypeNotFound)));
 
         // Create a badge type
-        client.create_badge_type(
-            &admin,
-            &1,
-            &String::from_str(&env, "Bronze"),
-            &500,
-            &10,
-            &0,
-        );
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 10, 0));
 
         // Disable badge type by running inside contract context
         env.as_contract(&contract_id, || {
@@ -89,6 +79,9 @@ mod test {
                 discount_bps: 500,
                 max_redemptions: 10,
                 validity_duration: 0,
+                uri: String::from_str(&env, ""),
+                redemption_cooldown: 0,
+                history_limit: 0,
                 enabled: false,
             };
             env.storage()
@@ -106,6 +99,9 @@ mod test {
                 discount_bps: 500,
                 max_redemptions: 10,
                 validity_duration: 0,
+                uri: String::from_str(&env, ""),
+                redemption_cooldown: 0,
+                history_limit: 0,
                 enabled: true,
             };
             env.storage()
@@ -137,14 +133,7 @@ mod test {
         let client = AcademyRewardsContractClient::new(&env, &contract_id);
 
         client.initialize(&admin);
-        client.create_badge_type(
-            &admin,
-            &1,
-            &String::from_str(&env, "Bronze"),
-            &500,
-            &1,
-            &10,
-        );
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 1, 10));
 
         // No badge
         let no_badge = client.try_redeem_badge(&user, &String::from_str(&env, "tx_0"));
@@ -160,8 +149,10 @@ mod test {
         // Reset time before expiry and redeem
         set_timestamp(&env, 1000 + 5);
         let tx_hash = String::from_str(&env, "tx_2");
-        let discount = client.redeem_badge(&user, &tx_hash);
-        assert_eq!(discount, 500);
+        let result = client.redeem_badge(&user, &tx_hash);
+        assert_eq!(result.discount_bps, 500);
+        assert_eq!(result.redemptions_remaining, 0);
+        assert!(result.badge_exhausted);
 
         // Redemption history
         let history = client.get_redemption_history(&user, &0).unwrap();
@@ -183,14 +174,7 @@ mod test {
         let client = AcademyRewardsContractClient::new(&env, &contract_id);
 
         client.initialize(&admin);
-        client.create_badge_type(
-            &admin,
-            &1,
-            &String::from_str(&env, "Bronze"),
-            &500,
-            &2,
-            &0,
-        );
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 2, 0));
         client.mint_badge(&admin, &user, &1);
 
         assert_eq!(client.get_user_discount(&user), 500);
@@ -211,14 +195,7 @@ mod test {
         let client = AcademyRewardsContractClient::new(&env, &contract_id);
 
         client.initialize(&admin);
-        client.create_badge_type(
-            &admin,
-            &1,
-            &String::from_str(&env, "Bronze"),
-            &500,
-            &1,
-            &0,
-        );
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 1, 0));
         client.mint_badge(&admin, &user, &1);
 
         let meta = client.get_badge_metadata(&1).unwrap();
@@ -236,9 +213,748 @@ mod test {
         assert_eq!(pause_err, Err(Ok(ContractError::Unauthorized)));
     }
 
+    #[test]
+    fn test_min_user_redemption_interval_across_badges() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+        client.set_min_user_redemption_interval(&admin, &100);
+        assert_eq!(client.get_min_user_redemption_interval(), 100);
+
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+
+        // Switching to a different badge does not reset the user's interval
+        client.mint_badge(&admin, &user, &2);
+        let too_soon = client.try_redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(too_soon, Err(Ok(ContractError::RedemptionTooSoon)));
+
+        set_timestamp(&env, 1000 + 100);
+        let result = client.redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(result.discount_bps, 1000);
+        assert_eq!(result.redemptions_remaining, u32::MAX);
+        assert!(!result.badge_exhausted);
+    }
+
+    #[test]
+    fn test_huge_redemption_interval_does_not_overflow() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.set_min_user_redemption_interval(&admin, &u64::MAX);
+
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+
+        let too_soon = client.try_redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(too_soon, Err(Ok(ContractError::RedemptionTooSoon)));
+    }
+
+    #[test]
+    fn test_min_hold_seconds_before_redemption() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.set_min_hold_seconds(&admin, &3600);
+        assert_eq!(client.get_min_hold_seconds(), 3600);
+
+        client.mint_badge(&admin, &user, &1);
+
+        set_timestamp(&env, 1000 + 3599);
+        let too_soon = client.try_redeem_badge(&user, &String::from_str(&env, "tx_a"));
+        assert_eq!(too_soon, Err(Ok(ContractError::HoldPeriodNotMet)));
+
+        set_timestamp(&env, 1000 + 3600);
+        let result = client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+        assert_eq!(result.discount_bps, 500);
+    }
+
+    #[test]
+    fn test_badge_stats_track_mints_redemptions_and_revocations() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let other = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+
+        client.mint_badge(&admin, &user, &1);
+        client.mint_badge(&admin, &other, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+        client.redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        client.redeem_badge(&other, &String::from_str(&env, "tx_c"));
+
+        let stats = client.get_badge_stats(&1);
+        assert_eq!(stats, BadgeStats { minted: 2, active: 2, total_redemptions: 3 });
+
+        client.revoke_badge(&admin, &other);
+        // Revoking twice does not double count
+        client.revoke_badge(&admin, &other);
+
+        let stats = client.get_badge_stats(&1);
+        assert_eq!(stats, BadgeStats { minted: 2, active: 1, total_redemptions: 3 });
+    }
+
+    #[test]
+    fn test_sweep_expired_badge() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 1));
+        client.mint_badge(&admin, &user, &1);
+
+        assert!(!client.is_badge_expired(&user));
+        let result = client.try_sweep_expired_badge(&admin, &user);
+        assert_eq!(result, Err(Ok(ContractError::BadgeNotExpired)));
+
+        set_timestamp(&env, 1002);
+        assert!(client.is_badge_expired(&user));
+        client.sweep_expired_badge(&admin, &user);
+
+        assert_eq!(client.get_user_discount(&user), 0);
+        assert_eq!(client.get_user_badge(&user), None);
+        assert!(!client.is_badge_expired(&user));
+        assert_eq!(client.get_badge_stats(&1).active, 0);
+
+        let result = client.try_sweep_expired_badge(&admin, &user);
+        assert_eq!(result, Err(Ok(ContractError::UserHasNoBadge)));
+    }
+
+    #[test]
+    fn test_mint_fee_charged_to_recipient() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let free_user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        let token_id = env.register_stellar_asset_contract(Address::generate(&env));
+        let token_client = token::Client::new(&env, &token_id);
+        token::StellarAssetClient::new(&env, &token_id).mint(&user, &1000);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+
+        // No fee configured: minting is free
+        assert_eq!(client.get_mint_fee(), None);
+        client.mint_badge(&admin, &free_user, &1);
+        assert!(client.get_user_badge(&free_user).is_some());
+
+        let result = client.try_set_mint_fee(&admin, &token_id, &-1, &treasury);
+        assert_eq!(result, Err(Ok(ContractError::InvalidMintFee)));
+
+        client.set_mint_fee(&admin, &token_id, &300, &treasury);
+        client.mint_badge(&admin, &user, &1);
+        assert_eq!(token_client.balance(&user), 700);
+        assert_eq!(token_client.balance(&treasury), 300);
+
+        // A recipient who cannot pay does not get the badge
+        let broke_user = Address::generate(&env);
+        let result = client.try_mint_badge(&admin, &broke_user, &1);
+        assert_eq!(result, Err(Ok(ContractError::MintFeeFailed)));
+        assert_eq!(client.get_user_badge(&broke_user), None);
+    }
+
+    #[test]
+    fn test_multiple_admins() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let second_admin = Address::generate(&env);
+        let other_user = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+
+        let result = client.try_mint_badge(&second_admin, &user, &1);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        client.add_admin(&admin, &second_admin);
+        assert!(client.is_admin(&second_admin));
+        client.mint_badge(&second_admin, &user, &1);
+        client.create_badge_type(&second_admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+
+        // The added admin has equal rights, including removing the original admin
+        client.remove_admin(&second_admin, &admin);
+        assert!(!client.is_admin(&admin));
+        let result = client.try_mint_badge(&admin, &other_user, &1);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        let result = client.try_remove_admin(&second_admin, &second_admin);
+        assert_eq!(result, Err(Ok(ContractError::LastAdmin)));
+
+        client.add_admin(&second_admin, &admin);
+        client.remove_admin(&admin, &second_admin);
+        let result = client.try_mint_badge(&second_admin, &other_user, &1);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    }
+
+    #[contract]
+    struct MockPool;
+
+    #[contractimpl]
+    impl MockPool {
+        pub fn set_stake(env: Env, user: Address, amount: i128) {
+            env.storage().persistent().set(&user, &amount);
+        }
+
+        pub fn get_user_stake(env: Env, user: Address) -> i128 {
+            env.storage().persistent().get(&user).unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_redemption_requires_active_stake() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let staker = Address::generate(&env);
+
+        let pool_id = env.register_contract(None, MockPool);
+        let pool = MockPoolClient::new(&env, &pool_id);
+        pool.set_stake(&staker, &100);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.mint_badge(&admin, &user, &1);
+        client.mint_badge(&admin, &staker, &1);
+
+        client.set_stake_requirement(&admin, &Some(pool_id.clone()));
+        assert_eq!(client.get_stake_requirement(), Some(pool_id));
+
+        let result = client.try_redeem_badge(&user, &String::from_str(&env, "tx_1"));
+        assert_eq!(result, Err(Ok(ContractError::NoActiveStake)));
+
+        assert_eq!(client.redeem_badge(&staker, &String::from_str(&env, "tx_2")).discount_bps, 500);
+
+        // Without a requirement anyone with a badge redeems again
+        client.set_stake_requirement(&admin, &None);
+        assert_eq!(client.redeem_badge(&user, &String::from_str(&env, "tx_3")).discount_bps, 500);
+    }
+
+    #[test]
+    fn test_per_type_redemption_cooldown() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.set_redemption_cooldown(&admin, &1, &600);
+        assert_eq!(client.get_badge_metadata(&1).unwrap().redemption_cooldown, 600);
+
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+
+        set_timestamp(&env, 1000 + 599);
+        let cooling = client.try_redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(cooling, Err(Ok(ContractError::RedemptionCooldownActive)));
+
+        set_timestamp(&env, 1000 + 600);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(client.get_user_badge(&user).unwrap().redeemed_count, 2);
+    }
+
+    #[test]
+    fn test_admin_redeem_bypasses_cooldown() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.set_redemption_cooldown(&admin, &1, &600);
+
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+
+        set_timestamp(&env, 1000 + 10);
+        let cooling = client.try_redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(cooling, Err(Ok(ContractError::RedemptionCooldownActive)));
+
+        let result = client.admin_redeem(&admin, &user, &String::from_str(&env, "tx_b"));
+        assert_eq!(result.discount_bps, 500);
+        assert_eq!(client.get_user_badge(&user).unwrap().redeemed_count, 2);
+
+        let admin_redeemed = env.events().all().iter().any(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+            name == Symbol::new(&env, "admin_redeemed")
+        });
+        assert!(admin_redeemed);
+
+        // The hash is still deduplicated
+        let reused = client.try_admin_redeem(&admin, &user, &String::from_str(&env, "tx_b"));
+        assert_eq!(reused, Err(Ok(ContractError::TransactionAlreadyRedeemed)));
+
+        let outsider = Address::generate(&env);
+        let denied = client.try_admin_redeem(&outsider, &user, &String::from_str(&env, "tx_c"));
+        assert_eq!(denied, Err(Ok(ContractError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_admin_redeem_rejects_expired_badge() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 100));
+        client.mint_badge(&admin, &user, &1);
+
+        // Forcing a redemption skips rate limits, not expiry
+        set_timestamp(&env, 1000 + 101);
+        let expired = client.try_admin_redeem(&admin, &user, &String::from_str(&env, "tx_a"));
+        assert_eq!(expired, Err(Ok(ContractError::BadgeExpired)));
+        assert_eq!(client.get_user_badge(&user).unwrap().redeemed_count, 0);
+    }
+
+    #[test]
+    fn test_history_limit_keeps_only_recent_records() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.set_history_limit(&admin, &1, &3);
+        client.mint_badge(&admin, &user, &1);
+
+        let hashes = ["tx_0", "tx_1", "tx_2", "tx_3", "tx_4"];
+        for hash in hashes.iter() {
+            client.redeem_badge(&user, &String::from_str(&env, hash));
+        }
+        assert_eq!(client.get_redemption_count(&user), 5);
+
+        // The two oldest records were overwritten
+        assert!(client.get_redemption_history(&user, &0).is_none());
+        assert!(client.get_redemption_history(&user, &1).is_none());
+        for index in 2..5u32 {
+            let record = client.get_redemption_history(&user, &index).unwrap();
+            assert_eq!(record.transaction_hash, String::from_str(&env, hashes[index as usize]));
+        }
+        assert!(client.get_redemption_history(&user, &5).is_none());
+
+        // Only K entries exist in storage
+        env.as_contract(&contract_id, || {
+            for slot in 0..5u32 {
+                let stored = env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::RedemptionHistory(user.clone(), slot));
+                assert_eq!(stored, slot < 3);
+            }
+        });
+    }
+
+    #[test]
+    fn test_history_limit_survives_recreate_and_upgrade() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+        client.set_history_limit(&admin, &1, &3);
+        client.set_redemption_cooldown(&admin, &1, &10);
+        client.mint_badge(&admin, &user, &1);
+
+        let hashes = ["tx_0", "tx_1", "tx_2", "tx_3", "tx_4"];
+        for (i, hash) in hashes.iter().enumerate() {
+            set_timestamp(&env, 1000 + 10 * i as u64);
+            client.redeem_badge(&user, &String::from_str(&env, hash));
+        }
+
+        // Once the type is held its limit is fixed
+        let locked = client.try_set_history_limit(&admin, &1, &5);
+        assert_eq!(locked, Err(Ok(ContractError::HistoryLimitLocked)));
+
+        // Re-creating the type keeps its limit and cooldown
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 600, 0, 0));
+        let metadata = client.get_badge_metadata(&1).unwrap();
+        assert_eq!(metadata.history_limit, 3);
+        assert_eq!(metadata.redemption_cooldown, 10);
+        for index in 2..5u32 {
+            let record = client.get_redemption_history(&user, &index).unwrap();
+            assert_eq!(record.transaction_hash, String::from_str(&env, hashes[index as usize]));
+        }
+
+        // Upgrading to an unlimited type re-slots the kept records
+        client.upgrade_badge(&admin, &user, &2);
+        assert!(client.get_redemption_history(&user, &1).is_none());
+        for index in 2..5u32 {
+            let record = client.get_redemption_history(&user, &index).unwrap();
+            assert_eq!(record.transaction_hash, String::from_str(&env, hashes[index as usize]));
+        }
+        client.redeem_badge(&user, &String::from_str(&env, "tx_5"));
+        let latest = client.get_redemption_history(&user, &5).unwrap();
+        assert_eq!(latest.transaction_hash, String::from_str(&env, "tx_5"));
+        assert_eq!(
+            client.get_redemption_history(&user, &2).unwrap().transaction_hash,
+            String::from_str(&env, "tx_2")
+        );
+    }
+
+    #[test]
+    fn test_can_redeem_mirrors_redeem_badge_checks() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let tx = String::from_str(&env, "tx_a");
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 1, 3600));
+
+        assert_eq!(client.try_can_redeem(&user, &tx), Err(Ok(ContractError::UserHasNoBadge)));
+
+        client.mint_badge(&admin, &user, &1);
+        assert_eq!(client.can_redeem(&user, &tx), 500);
+
+        // The precheck does not consume the hash or the redemption
+        assert_eq!(client.get_redemption_count(&user), 0);
+
+        client.set_paused(&admin, &true);
+        assert_eq!(client.try_can_redeem(&user, &tx), Err(Ok(ContractError::ContractPaused)));
+        client.set_paused(&admin, &false);
+
+        client.redeem_badge(&user, &tx);
+        assert_eq!(client.try_can_redeem(&user, &tx), Err(Ok(ContractError::TransactionAlreadyRedeemed)));
+
+        let tx_b = String::from_str(&env, "tx_b");
+        assert_eq!(client.try_can_redeem(&user, &tx_b), Err(Ok(ContractError::RedemptionLimitReached)));
+
+        set_timestamp(&env, 1000 + 3601);
+        assert_eq!(client.try_can_redeem(&user, &tx_b), Err(Ok(ContractError::BadgeExpired)));
+
+        client.revoke_badge(&admin, &user);
+        assert_eq!(client.try_can_redeem(&user, &tx_b), Err(Ok(ContractError::BadgeNotActive)));
+    }
+
+    #[test]
+    fn test_badge_type_enumeration() {
+        let (env, admin, _user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        assert_eq!(client.get_badge_types().len(), 0);
+
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+        client.create_badge_type(&admin, &3, &badge_config(&env, "Gold", 2000, 0, 0));
+
+        // Re-creating an existing type updates it without duplicating the index entry
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1500, 0, 0));
+
+        let types = client.get_badge_types();
+        assert_eq!(types.len(), 3);
+        assert_eq!(types.get(0), Some(1));
+        assert_eq!(types.get(1), Some(2));
+        assert_eq!(types.get(2), Some(3));
+
+        let all = client.get_all_badge_metadata();
+        assert_eq!(all.len(), 3);
+        let (badge_type, silver) = all.get(1).unwrap();
+        assert_eq!(badge_type, 2);
+        assert_eq!(silver.discount_bps, 1500);
+    }
+
+    #[test]
+    fn test_extend_badge() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let other = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 100));
+        client.mint_badge(&admin, &user, &1);
+        client.mint_badge(&admin, &other, &1);
+
+        // Live badge: expiry moves out and the badge stays redeemable past the old expiry
+        client.extend_badge(&admin, &user, &50);
+        assert_eq!(client.get_user_badge(&user).unwrap().expiry, 1000 + 150);
+
+        set_timestamp(&env, 1000 + 120);
+        assert_eq!(client.get_user_discount(&user), 500);
+
+        // Expired badge cannot be extended
+        let expired = client.try_extend_badge(&admin, &other, &50);
+        assert_eq!(expired, Err(Ok(ContractError::BadgeExpired)));
+        assert_eq!(client.get_user_badge(&other).unwrap().expiry, 1000 + 100);
+
+        // Revoked badge cannot be extended
+        client.revoke_badge(&admin, &user);
+        let revoked = client.try_extend_badge(&admin, &user, &50);
+        assert_eq!(revoked, Err(Ok(ContractError::BadgeNotActive)));
+    }
+
+    #[test]
+    fn test_min_validity_duration() {
+        let (env, admin, _user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.set_min_validity_duration(&admin, &86400);
+        assert_eq!(client.get_min_validity_duration(), 86400);
+
+        let too_short = client.try_create_badge_type(&admin, &1, &badge_config(&env, "Flash", 500, 0, 60));
+        assert_eq!(too_short, Err(Ok(ContractError::ValidityTooShort)));
+        assert!(client.get_badge_metadata(&1).is_none());
+
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Monthly", 500, 0, 2_592_000));
+        assert_eq!(client.get_badge_metadata(&2).unwrap().validity_duration, 2_592_000);
+
+        // Never-expiring badge types are exempt from the floor
+        client.create_badge_type(&admin, &3, &badge_config(&env, "Lifetime", 500, 0, 0));
+        assert!(client.get_badge_metadata(&3).is_some());
+    }
+
+    #[test]
+    fn test_transfer_badge_migrates_badge_and_history() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let new_wallet = Address::generate(&env);
+        let holder = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+
+        client.transfer_badge(&admin, &user, &new_wallet);
+
+        assert!(client.get_user_badge(&user).is_none());
+        assert_eq!(client.get_user_discount(&user), 0);
+        assert!(client.get_redemption_history(&user, &0).is_none());
+
+        let badge = client.get_user_badge(&new_wallet).unwrap();
+        assert_eq!(badge.badge_type, 1);
+        assert_eq!(badge.redeemed_count, 1);
+        let history = client.get_redemption_history(&new_wallet, &0).unwrap();
+        assert_eq!(history.transaction_hash, String::from_str(&env, "tx_a"));
+        assert_eq!(client.get_user_total_discount(&new_wallet), 500);
+        assert_eq!(client.get_user_total_discount(&user), 0);
+
+        // Destination already holding an active badge of the same type is rejected
+        client.mint_badge(&admin, &holder, &1);
+        let occupied = client.try_transfer_badge(&admin, &new_wallet, &holder);
+        assert_eq!(occupied, Err(Ok(ContractError::UserAlreadyHasBadge)));
+
+        let missing = client.try_transfer_badge(&admin, &user, &holder);
+        assert_eq!(missing, Err(Ok(ContractError::UserHasNoBadge)));
+    }
+
+    #[test]
+    fn test_transfer_badge_rejects_self_and_occupied_destinations() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let holder = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+
+        // Transferring to yourself would wipe the badge and its history
+        let to_self = client.try_transfer_badge(&admin, &user, &user);
+        assert_eq!(to_self, Err(Ok(ContractError::InvalidTransfer)));
+        assert_eq!(client.get_user_badge(&user).unwrap().redeemed_count, 1);
+        assert!(client.get_redemption_history(&user, &0).is_some());
+
+        // An inactive badge of another type on the destination is not replaced either
+        client.mint_badge(&admin, &holder, &2);
+        client.revoke_badge(&admin, &holder);
+        let occupied = client.try_transfer_badge(&admin, &user, &holder);
+        assert_eq!(occupied, Err(Ok(ContractError::UserAlreadyHasBadge)));
+        assert_eq!(client.get_user_badge(&holder).unwrap().badge_type, 2);
+    }
+
+    #[test]
+    fn test_upgrade_badge_preserves_redemptions() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 10, 0));
+        client.create_badge_type(&admin, &3, &badge_config(&env, "Gold", 2000, 20, 0));
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+        let result = client.redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(result.redemptions_remaining, 8);
+        assert!(!result.badge_exhausted);
+
+        client.upgrade_badge(&admin, &user, &3);
+
+        let badge = client.get_user_badge(&user).unwrap();
+        assert_eq!(badge.badge_type, 3);
+        assert_eq!(badge.discount_bps, 2000);
+        assert_eq!(badge.max_redemptions, 20);
+        assert_eq!(badge.redeemed_count, 2);
+        assert_eq!(client.get_user_discount(&user), 2000);
+
+        let downgrade = client.try_upgrade_badge(&admin, &user, &1);
+        assert_eq!(downgrade, Err(Ok(ContractError::InvalidUpgrade)));
+
+        let missing_type = client.try_upgrade_badge(&admin, &user, &9);
+        assert_eq!(missing_type, Err(Ok(ContractError::BadgeTypeNotFound)));
+    }
+
+    #[test]
+    fn test_badge_events_carry_badge_type_topic() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+        client.mint_badge(&admin, &user, &2);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+        client.revoke_badge(&admin, &user);
+
+        let mut seen = 0;
+        for (_, topics, _) in env.events().all().iter() {
+            let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+            if name == Symbol::new(&env, "badge_minted")
+                || name == Symbol::new(&env, "badge_redeemed")
+                || name == Symbol::new(&env, "badge_revoked")
+            {
+                let badge_type: u32 = topics.get(1).unwrap().try_into_val(&env).unwrap();
+                assert_eq!(badge_type, 2);
+                seen += 1;
+            }
+        }
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn test_user_total_discount_accumulates() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+        client.create_badge_type(&admin, &1, &badge_config(&env, "Bronze", 500, 0, 0));
+        client.create_badge_type(&admin, &2, &badge_config(&env, "Silver", 1000, 0, 0));
+        assert_eq!(client.get_user_total_discount(&user), 0);
+
+        client.mint_badge(&admin, &user, &1);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_a"));
+        client.redeem_badge(&user, &String::from_str(&env, "tx_b"));
+        assert_eq!(client.get_user_total_discount(&user), 1000);
+
+        // Totals carry across badge types
+        client.upgrade_badge(&admin, &user, &2);
+        client.redeem_badge(&user, &String::from_str(&env, "tx_c"));
+        assert_eq!(client.get_user_total_discount(&user), 2000);
+    }
+
+    #[test]
+    fn test_badge_type_uri_round_trip_and_length_limit() {
+        let (env, admin, _user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin);
+
+        let uri = String::from_str(&env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+        client.create_badge_type(&admin, &1, &BadgeTypeConfig { uri: uri.clone(), ..badge_config(&env, "Bronze", 500, 0, 0) });
+        assert_eq!(client.get_badge_metadata(&1).unwrap().uri, uri);
+
+        let too_long = String::from_bytes(&env, &[b'a'; 257]);
+        let result = client.try_create_badge_type(&admin, &2, &BadgeTypeConfig { uri: too_long, ..badge_config(&env, "Silver", 1000, 0, 0) });
+        assert_eq!(result, Err(Ok(ContractError::UriTooLong)));
+    }
+
     fn set_timestamp(env: &Env, timestamp: u64) {
         let mut ledger_info = env.ledger().get();
         ledger_info.timestamp = timestamp;
         env.ledger().set(ledger_info);
     }
+
+    fn sign_voucher(env: &Env, contract_id: &Address, key: &SigningKey, voucher: &Voucher) -> BytesN<64> {
+        extern crate std;
+        let message: std::vec::Vec<u8> = (contract_id.clone(), voucher.clone()).to_xdr(env).iter().collect();
+        BytesN::from_array(env, &key.sign(&message).to_bytes())
+    }
+
+    fn setup_vouchers(env: &Env, admin: &Address, contract_id: &Address) -> SigningKey {
+        let client = AcademyRewardsContractClient::new(env, contract_id);
+        client.initialize(admin);
+
+        let signer = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = BytesN::from_array(env, &signer.verifying_key().to_bytes());
+        client.set_voucher_signer(admin, &public_key);
+        assert_eq!(client.get_voucher_signer(), Some(public_key));
+        signer
+    }
+
+    #[test]
+    fn test_redeem_voucher_once() {
+        let (env, admin, user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let signer = setup_vouchers(&env, &admin, &contract_id);
+
+        // No badge is needed to redeem a signed voucher
+        let voucher = Voucher { id: 1, user: user.clone(), discount_bps: 1500, expires_at: 0 };
+        let signature = sign_voucher(&env, &contract_id, &signer, &voucher);
+        assert_eq!(client.redeem_voucher(&user, &voucher, &signature), 1500);
+        assert_eq!(client.get_user_total_discount(&user), 1500);
+
+        // Replaying the same voucher is rejected
+        let replayed = client.try_redeem_voucher(&user, &voucher, &signature);
+        assert_eq!(replayed, Err(Ok(ContractError::VoucherAlreadyRedeemed)));
+
+        // Vouchers are bound to their user
+        let other = Address::generate(&env);
+        let voucher = Voucher { id: 2, user: user.clone(), discount_bps: 500, expires_at: 0 };
+        let signature = sign_voucher(&env, &contract_id, &signer, &voucher);
+        let stolen = client.try_redeem_voucher(&other, &voucher, &signature);
+        assert_eq!(stolen, Err(Ok(ContractError::InvalidVoucher)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Crypto, InvalidInput)")]
+    fn test_redeem_forged_voucher_fails() {
+        let (env, admin, user, contract_id) = setup_env();
+        setup_vouchers(&env, &admin, &contract_id);
+
+        let forger = SigningKey::from_bytes(&[9u8; 32]);
+        let voucher = Voucher { id: 1, user: user.clone(), discount_bps: 10000, expires_at: 0 };
+        let signature = sign_voucher(&env, &contract_id, &forger, &voucher);
+
+        let _ = env.as_contract(&contract_id, || {
+            AcademyRewardsContract::redeem_voucher(env.clone(), user.clone(), voucher.clone(), signature.clone())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Crypto, InvalidInput)")]
+    fn test_redeem_tampered_voucher_fails() {
+        let (env, admin, user, contract_id) = setup_env();
+        let signer = setup_vouchers(&env, &admin, &contract_id);
+
+        let voucher = Voucher { id: 1, user: user.clone(), discount_bps: 500, expires_at: 0 };
+        let signature = sign_voucher(&env, &contract_id, &signer, &voucher);
+        let tampered = Voucher { discount_bps: 9000, ..voucher };
+
+        let _ = env.as_contract(&contract_id, || {
+            AcademyRewardsContract::redeem_voucher(env.clone(), user.clone(), tampered.clone(), signature.clone())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Crypto, InvalidInput)")]
+    fn test_voucher_does_not_replay_on_another_deployment() {
+        let (env, admin, user, contract_id) = setup_env();
+        let signer = setup_vouchers(&env, &admin, &contract_id);
+
+        // A second deployment trusting the same signer
+        let other_id = env.register_contract(None, AcademyRewardsContract);
+        let other = AcademyRewardsContractClient::new(&env, &other_id);
+        other.initialize(&admin);
+        other.set_voucher_signer(&admin, &BytesN::from_array(&env, &signer.verifying_key().to_bytes()));
+
+        let voucher = Voucher { id: 1, user: user.clone(), discount_bps: 1500, expires_at: 0 };
+        let signature = sign_voucher(&env, &contract_id, &signer, &voucher);
+
+        let _ = env.as_contract(&other_id, || {
+            AcademyRewardsContract::redeem_voucher(env.clone(), user.clone(), voucher.clone(), signature.clone())
+        });
+    }
 }
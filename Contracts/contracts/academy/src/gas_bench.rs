@@ -32,7 +32,7 @@ mod gas_benchmarks {
         let duration = 1000u64;
         let amount = 1000i128;
         let before = env.ledger().timestamp();
-        let _ = AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary, amount, start_time, cliff, duration);
+        let _ = AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary, amount, start_time, duration, GrantTerms { cliff, cliff_unlock_bps: 0, transferable: true });
         let after = env.ledger().timestamp();
         println!("grant_vesting gas: {}", after - before);
     }
@@ -45,14 +45,14 @@ mod gas_benchmarks {
         let cliff = 100u64;
         let duration = 1000u64;
         let amount = 1000i128;
-        let _ = AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), amount, start_time, cliff, duration);
+        let _ = AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), amount, start_time, duration, GrantTerms { cliff, cliff_unlock_bps: 0, transferable: true });
 
         let token_admin = token::StellarAssetClient::new(&env, &reward_token);
         token_admin.mint(&env.current_contract_address(), &amount);
 
         set_timestamp(&env, start_time + cliff + 500);
         let before = env.ledger().timestamp();
-        let _ = AcademyVestingContract::claim(env.clone(), 1, beneficiary);
+        let _ = AcademyVestingContract::claim(env.clone(), 1, beneficiary, None);
         let after = env.ledger().timestamp();
         println!("claim gas: {}", after - before);
     }
@@ -3,5 +3,5 @@
 pub mod vesting;
 
 pub use vesting::{
-    AcademyVestingContract, VestingSchedule, GrantEvent, ClaimEvent, RevokeEvent, VestingError,
+    AcademyVestingContract, VestingSchedule, GrantTerms, GrantEvent, ClaimEvent, RevokeEvent, SweepEvent, TransferEvent, VestingError,
 };
@@ -27,11 +27,11 @@ mod regression {
         let token_admin = token::StellarAssetClient::new(&env, &token_id);
 
         client.init(&admin, &token_id, &governance);
-        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &0, &1000);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &1000, &GrantTerms { cliff: 0, cliff_unlock_bps: 0, transferable: true });
 
         token_admin.mint(&contract_id, &1000);
         set_timestamp(&env, 1000);
-        let _ = client.claim(&grant_id, &beneficiary);
+        let _ = client.claim(&grant_id, &beneficiary, &None);
         // If any optimization breaks logic, this will fail
         let schedule = client.get_vesting(&grant_id);
         assert!(schedule.claimed);
@@ -1,4 +1,4 @@
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, symbol_short, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, BytesN, Env, IntoVal, String, Symbol, symbol_short, Vec};
 
 /// Vesting schedule for an academy reward
 #[contracttype]
@@ -9,9 +9,22 @@ pub struct VestingSchedule {
     pub start_time: u64,
     pub cliff: u64,                    // Time (in seconds) before any tokens unlock
     pub duration: u64,                 // Total vesting duration (in seconds)
+    pub cliff_unlock_bps: u32,         // Share of amount (in bps) unlocked at the cliff
+    pub transferable: bool,            // Whether the beneficiary may hand the grant over
     pub claimed: bool,
     pub revoked: bool,
     pub revoke_time: u64,              // When it was revoked (0 if not revoked)
+    pub revoke_window: u64,            // Claim window (seconds) in force when it was revoked
+    pub claimed_amount: i128,          // Principal paid out by claim (fee included)
+}
+
+/// Cliff and transfer terms of a new grant
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct GrantTerms {
+    pub cliff: u64,                    // Time (in seconds) before any tokens unlock
+    pub cliff_unlock_bps: u32,         // Share of amount (in bps) unlocked at the cliff
+    pub transferable: bool,            // Whether the beneficiary may hand the grant over
 }
 
 /// Vesting grant event for off-chain indexing
@@ -24,8 +37,10 @@ pub struct GrantEvent {
     pub start_time: u64,
     pub cliff: u64,
     pub duration: u64,
+    pub cliff_unlock_bps: u32,
     pub granted_at: u64,
     pub granted_by: Address,
+    pub is_backdated: bool,            // start_time earlier than granted_at
 }
 
 /// Claim event for off-chain indexing
@@ -35,6 +50,8 @@ pub struct ClaimEvent {
     pub grant_id: u64,
     pub beneficiary: Address,
     pub amount: i128,
+    pub fee: i128,                     // Claim fee withheld from amount
+    pub bonus: i128,                   // Patience bonus included in amount
     pub claimed_at: u64,
 }
 
@@ -48,6 +65,27 @@ pub struct RevokeEvent {
     pub revoked_by: Address,
 }
 
+/// Sweep event for off-chain indexing
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SweepEvent {
+    pub grant_id: u64,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub swept_at: u64,
+    pub swept_by: Address,
+}
+
+/// Grant transfer event for off-chain indexing
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TransferEvent {
+    pub grant_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub transferred_at: u64,
+}
+
 /// Vesting error codes
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -61,6 +99,20 @@ pub enum VestingError {
     Revoked = 4007,
     InvalidTimelock = 4008,
     NotEnoughTimeForRevoke = 4009,
+    Paused = 4010,
+    NotTransferable = 4011,
+    ClaimWindowOpen = 4012,
+    GrantPaused = 4013,
+    NotAccepted = 4014,
+    NoticePeriodActive = 4015,
+    RevocationNotPending = 4016,
+    UpgradeTimelockActive = 4017,
+    NoUpgradePending = 4018,
+    ExternalRefUsed = 4019,
+    BeneficiaryNotRegistered = 4020,
+    GloballyHalted = 4021,
+    NotPending = 4022,
+    ExternalRefTooLong = 4023,
 }
 
 impl From<VestingError> for soroban_sdk::Error {
@@ -81,6 +133,15 @@ impl From<soroban_sdk::Error> for VestingError {
     }
 }
 
+/// Minimum revocation timelock (seconds) until governance changes it
+pub const DEFAULT_MIN_REVOKE_DELAY: u64 = 3600;
+
+/// Shortest upgrade timelock (seconds) propose_upgrade accepts
+pub const MIN_UPGRADE_DELAY: u64 = 86400;
+
+/// Maximum length in bytes of a claim's bridge external_ref
+pub const MAX_EXTERNAL_REF_LEN: u32 = 128;
+
 #[contract]
 pub struct AcademyVestingContract;
 
@@ -118,6 +179,15 @@ impl AcademyVestingContract {
         let counter_key = symbol_short!("cnt");
         env.storage().persistent().set(&counter_key, &0u64);
 
+        // Default minimum revocation timelock (1 hour)
+        let min_delay_key = symbol_short!("min_rvk");
+        env.storage().persistent().set(&min_delay_key, &DEFAULT_MIN_REVOKE_DELAY);
+
+        env.events().publish(
+            (Symbol::new(&env, "initialized"),),
+            (admin, reward_token, governance),
+        );
+
         Ok(())
     }
 
@@ -128,10 +198,11 @@ impl AcademyVestingContract {
         beneficiary: Address,
         amount: i128,
         start_time: u64,
-        cliff: u64,
         duration: u64,
+        terms: GrantTerms,
     ) -> Result<u64, VestingError> {
         admin.require_auth();
+        let GrantTerms { cliff, cliff_unlock_bps, transferable } = terms;
 
         // Verify caller is admin
         let admin_key = symbol_short!("admin");
@@ -152,6 +223,15 @@ impl AcademyVestingContract {
         if cliff > duration {
             return Err(VestingError::InvalidSchedule);
         }
+        if cliff_unlock_bps > 10000 {
+            return Err(VestingError::InvalidSchedule);
+        }
+        let (min_duration, max_duration) = Self::get_duration_bounds(env.clone());
+        if duration < min_duration || duration > max_duration {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        Self::require_registered(&env, &beneficiary)?;
 
         // Get next grant ID
         let counter_key = symbol_short!("cnt");
@@ -170,9 +250,13 @@ impl AcademyVestingContract {
             start_time,
             cliff,
             duration,
+            cliff_unlock_bps,
+            transferable,
             claimed: false,
             revoked: false,
             revoke_time: 0,
+            revoke_window: 0,
+            claimed_amount: 0,
         };
 
         // Store schedule
@@ -185,6 +269,13 @@ impl AcademyVestingContract {
 
         schedules.set(next_id, schedule);
         env.storage().persistent().set(&schedules_key, &schedules);
+        Self::index_grant(&env, &beneficiary, next_id);
+
+        // In acceptance mode the grant stays locked until the beneficiary confirms it
+        if Self::get_require_acceptance(env.clone()) {
+            let pending_key = (symbol_short!("pending"), next_id);
+            env.storage().persistent().set(&pending_key, &true);
+        }
 
         // Update counter
         env.storage()
@@ -192,6 +283,8 @@ impl AcademyVestingContract {
             .set(&counter_key, &next_id);
 
         // Emit grant event
+        let granted_at = env.ledger().timestamp();
+        let is_backdated = start_time < granted_at;
         let grant_event = GrantEvent {
             grant_id: next_id,
             beneficiary,
@@ -199,19 +292,54 @@ impl AcademyVestingContract {
             start_time,
             cliff,
             duration,
-            granted_at: env.ledger().timestamp(),
+            cliff_unlock_bps,
+            granted_at,
             granted_by: admin,
+            is_backdated,
         };
 
         env.events().publish((symbol_short!("grant"),), grant_event);
 
+        // Flag backdated grants under a distinct topic for compliance review
+        if is_backdated {
+            env.events().publish(
+                (symbol_short!("backdate"),),
+                (next_id, start_time, granted_at),
+            );
+        }
+
         Ok(next_id)
     }
 
     /// Claim vested tokens (atomic operation, single-claim semantics)
-    pub fn claim(env: Env, grant_id: u64, beneficiary: Address) -> Result<i128, VestingError> {
+    pub fn claim(
+        env: Env,
+        grant_id: u64,
+        beneficiary: Address,
+        external_ref: Option<String>,
+    ) -> Result<i128, VestingError> {
         beneficiary.require_auth();
 
+        // Claims are halted while the contract is paused
+        if Self::is_paused(env.clone()) {
+            return Err(VestingError::Paused);
+        }
+
+        // ...and while the shared emergency registry reports a global halt
+        if Self::is_globally_halted(&env) {
+            return Err(VestingError::GloballyHalted);
+        }
+
+        // Individually paused grants stay locked while others remain claimable
+        if Self::is_grant_paused(env.clone(), grant_id) {
+            return Err(VestingError::GrantPaused);
+        }
+
+        // Unconfirmed grants cannot be claimed until the beneficiary accepts them
+        if !Self::is_grant_accepted(env.clone(), grant_id) {
+            return Err(VestingError::NotAccepted);
+        }
+
         // Get vesting schedule
         let schedules_key = symbol_short!("sched");
         let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
@@ -234,16 +362,34 @@ impl AcademyVestingContract {
             return Err(VestingError::AlreadyClaimed);
         }
 
-        // Check if revoked
-        if schedule.revoked {
+        // A bridge reference may bind only one claim
+        if let Some(external_ref) = &external_ref {
+            if external_ref.len() > MAX_EXTERNAL_REF_LEN {
+                return Err(VestingError::ExternalRefTooLong);
+            }
+            let ref_key = (symbol_short!("ext_ref"), external_ref.clone());
+            if env.storage().persistent().has(&ref_key) {
+                return Err(VestingError::ExternalRefUsed);
+            }
+        }
+
+        // Revoked grants stay claimable (as vested at revocation) during the claim window
+        let current_time = env.ledger().timestamp();
+        if schedule.revoked && !Self::in_revoke_claim_window(&schedule, current_time) {
             return Err(VestingError::Revoked);
         }
 
         // Calculate vested amount
-        let current_time = env.ledger().timestamp();
-        let vested_amount = Self::calculate_vested_amount(
+        let vested_at = if schedule.revoked {
+            schedule.revoke_time
+        } else {
+            current_time
+        };
+        let vested_amount = Self::vested_with_top_ups(
+            &env,
+            grant_id,
             &schedule,
-            current_time,
+            vested_at,
         )?;
 
         if vested_amount == 0 {
@@ -258,59 +404,140 @@ impl AcademyVestingContract {
             .get(&token_key)
             .ok_or(VestingError::Unauthorized)?;
 
+        // The patience bonus pool shares the contract balance but is not grant principal
         let token_client = soroban_sdk::token::Client::new(&env, &token);
         let balance = token_client.balance(&env.current_contract_address());
 
-        if balance < vested_amount {
+        if balance - Self::get_bonus_pool(env.clone()) < vested_amount {
             return Err(VestingError::InsufficientBalance);
         }
 
         // Mark as claimed (atomic operation)
         schedule.claimed = true;
+        schedule.claimed_amount = vested_amount;
         schedules.set(grant_id, schedule.clone());
         env.storage().persistent().set(&schedules_key, &schedules);
 
+        if let Some(external_ref) = external_ref {
+            let ref_key = (symbol_short!("ext_ref"), external_ref.clone());
+            env.storage().persistent().set(&ref_key, &grant_id);
+            let claim_ref_key = (symbol_short!("claim_ref"), grant_id);
+            env.storage().persistent().set(&claim_ref_key, &external_ref);
+        }
+
+        // Claiming before full vesting forfeits the patience bonus; otherwise pay it from the pool
+        let fully_vested = !schedule.revoked
+            && current_time >= schedule.start_time.saturating_add(schedule.duration);
+        let bonus = if fully_vested {
+            Self::take_patience_bonus(&env, schedule.amount)
+        } else {
+            let early_key = (symbol_short!("early"), grant_id);
+            env.storage().persistent().set(&early_key, &true);
+            0
+        };
+
+        // Withhold the claim fee, reduced by the beneficiary's academy badge discount
+        let fee = Self::claim_fee(&env, &beneficiary, vested_amount);
+        let payout = vested_amount - fee + bonus;
+
+        if fee > 0 {
+            let fee_recipient_key = symbol_short!("fee_to");
+            let fee_recipient: Address = env
+                .storage()
+                .persistent()
+                .get(&fee_recipient_key)
+                .ok_or(VestingError::Unauthorized)?;
+            token_client.transfer(&env.current_contract_address(), &fee_recipient, &fee);
+        }
+
         // Transfer tokens
         token_client.transfer(
             &env.current_contract_address(),
             &beneficiary,
-            &vested_amount,
+            &payout,
         );
 
         // Emit claim event
         let claim_event = ClaimEvent {
             grant_id,
             beneficiary,
-            amount: vested_amount,
+            amount: payout,
+            fee,
+            bonus,
             claimed_at: env.ledger().timestamp(),
         };
 
         env.events().publish((symbol_short!("claim"),), claim_event);
 
-        Ok(vested_amount)
+        Ok(payout)
     }
 
-    /// Revoke a vesting schedule (governance/admin only, with timelock)
+    /// Revoke a vesting schedule (governance only, with timelock)
     pub fn revoke(
         env: Env,
         grant_id: u64,
-        admin: Address,
+        governance: Address,
         revoke_delay: u64,
     ) -> Result<(), VestingError> {
-        admin.require_auth();
+        Self::require_governance(&env, &governance)?;
+        Self::revoke_grant(env, grant_id, governance, revoke_delay)
+    }
 
-        // Verify caller is admin
-        let admin_key = symbol_short!("admin");
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&admin_key)
-            .ok_or(VestingError::Unauthorized)?;
+    /// Revoke every eligible grant of a beneficiary (governance only)
+    ///
+    /// Returns `(revoked, scheduled)`: grants revoked now, and grants whose revocation was only
+    /// scheduled because a notice period is set. Claimed and revoked grants, grants still inside
+    /// their revoke timelock and grants with a revocation already pending are skipped.
+    pub fn revoke_all_for_beneficiary(
+        env: Env,
+        governance: Address,
+        beneficiary: Address,
+        revoke_delay: u64,
+    ) -> Result<(u32, u32), VestingError> {
+        Self::require_governance(&env, &governance)?;
 
-        if admin != stored_admin {
-            return Err(VestingError::Unauthorized);
+        // Enforce the configured minimum revocation timelock
+        if revoke_delay < Self::get_min_revoke_delay(env.clone()) {
+            return Err(VestingError::InvalidTimelock);
         }
 
+        let mut revoked: u32 = 0;
+        let mut scheduled: u32 = 0;
+        for grant_id in Self::get_beneficiary_grants(env.clone(), beneficiary).iter() {
+            match Self::revoke_grant(env.clone(), grant_id, governance.clone(), revoke_delay) {
+                Ok(()) => {
+                    if Self::get_pending_revocation(env.clone(), grant_id).is_some() {
+                        scheduled += 1;
+                    } else {
+                        revoked += 1;
+                    }
+                }
+                Err(VestingError::AlreadyClaimed)
+                | Err(VestingError::Revoked)
+                | Err(VestingError::NotEnoughTimeForRevoke)
+                | Err(VestingError::NoticePeriodActive) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok((revoked, scheduled))
+    }
+
+    /// Get the ids of every grant currently held by `beneficiary`
+    pub fn get_beneficiary_grants(env: Env, beneficiary: Address) -> Vec<u64> {
+        let index_key = (symbol_short!("by_ben"), beneficiary);
+        env.storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn revoke_grant(
+        env: Env,
+        grant_id: u64,
+        governance: Address,
+        revoke_delay: u64,
+    ) -> Result<(), VestingError> {
         // Get vesting schedule
         let schedules_key = symbol_short!("sched");
         let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
@@ -333,8 +560,8 @@ impl AcademyVestingContract {
             return Err(VestingError::Revoked);
         }
 
-        // Enforce timelock for revocation (minimum 1 hour)
-        if revoke_delay < 3600 {
+        // Enforce the configured minimum revocation timelock
+        if revoke_delay < Self::get_min_revoke_delay(env.clone()) {
             return Err(VestingError::InvalidTimelock);
         }
 
@@ -344,9 +571,28 @@ impl AcademyVestingContract {
             return Err(VestingError::NotEnoughTimeForRevoke);
         }
 
+        // A scheduled revocation must go through finalize_revoke, even if the notice period was since lowered
+        let pending_key = (symbol_short!("rv_pend"), grant_id);
+        if env.storage().persistent().has(&pending_key) {
+            return Err(VestingError::NoticePeriodActive);
+        }
+
+        // With a notice period, only schedule the revocation; finalize_revoke completes it
+        let notice_period = Self::get_revoke_notice_period(env.clone());
+        if notice_period > 0 {
+            let effective_at = current_time.saturating_add(notice_period);
+            env.storage().persistent().set(&pending_key, &effective_at);
+            env.events().publish(
+                (Symbol::new(&env, "revocation_pending"),),
+                (grant_id, schedule.beneficiary, effective_at),
+            );
+            return Ok(());
+        }
+
         // Mark as revoked
         schedule.revoked = true;
         schedule.revoke_time = current_time;
+        schedule.revoke_window = Self::get_revoke_claim_window(env.clone());
         schedules.set(grant_id, schedule.clone());
         env.storage().persistent().set(&schedules_key, &schedules);
 
@@ -355,7 +601,7 @@ impl AcademyVestingContract {
             grant_id,
             beneficiary: schedule.beneficiary,
             revoked_at: current_time,
-            revoked_by: admin,
+            revoked_by: governance,
         };
 
         env.events().publish((symbol_short!("revoke"),), revoke_event);
@@ -363,70 +609,1069 @@ impl AcademyVestingContract {
         Ok(())
     }
 
-    /// Query vesting schedule details
-    pub fn get_vesting(env: Env, grant_id: u64) -> Result<VestingSchedule, VestingError> {
+    /// Complete a revocation scheduled under a notice period once the notice has elapsed (governance only)
+    ///
+    /// If the beneficiary claimed during the notice period, the pending entry is cleared and nothing is revoked.
+    pub fn finalize_revoke(
+        env: Env,
+        grant_id: u64,
+        governance: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_governance(&env, &governance)?;
+
+        let pending_key = (symbol_short!("rv_pend"), grant_id);
+        let effective_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&pending_key)
+            .ok_or(VestingError::RevocationNotPending)?;
+
+        let current_time = env.ledger().timestamp();
+        if current_time < effective_at {
+            return Err(VestingError::NoticePeriodActive);
+        }
+
         let schedules_key = symbol_short!("sched");
-        let schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
             .storage()
             .persistent()
             .get(&schedules_key)
             .ok_or(VestingError::GrantNotFound)?;
 
-        schedules
+        let mut schedule = schedules
             .get(grant_id)
-            .ok_or(VestingError::GrantNotFound)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        if schedule.revoked {
+            return Err(VestingError::Revoked);
+        }
+
+        env.storage().persistent().remove(&pending_key);
+
+        // The beneficiary may have claimed during the notice period
+        if schedule.claimed {
+            return Ok(());
+        }
+
+        // Vesting stops at the announced effective time
+        schedule.revoked = true;
+        schedule.revoke_time = effective_at;
+        schedule.revoke_window = Self::get_revoke_claim_window(env.clone());
+        schedules.set(grant_id, schedule.clone());
+        env.storage().persistent().set(&schedules_key, &schedules);
+
+        let revoke_event = RevokeEvent {
+            grant_id,
+            beneficiary: schedule.beneficiary,
+            revoked_at: effective_at,
+            revoked_by: governance,
+        };
+
+        env.events().publish((symbol_short!("revoke"),), revoke_event);
+
+        Ok(())
     }
 
-    /// Calculate vested amount at current time
-    pub fn get_vested_amount(env: Env, grant_id: u64) -> Result<i128, VestingError> {
+    /// Get the external reference a grant was claimed with, if any
+    pub fn get_claim_ref(env: Env, grant_id: u64) -> Option<String> {
+        let claim_ref_key = (symbol_short!("claim_ref"), grant_id);
+        env.storage().persistent().get(&claim_ref_key)
+    }
+
+    /// Get when a scheduled revocation takes effect, if one is pending
+    pub fn get_pending_revocation(env: Env, grant_id: u64) -> Option<u64> {
+        let pending_key = (symbol_short!("rv_pend"), grant_id);
+        env.storage().persistent().get(&pending_key)
+    }
+
+    /// Return the unclaimed part of a revoked grant to the admin (admin only)
+    ///
+    /// Unclaimed grants are swept in full once their claim window has closed; grants claimed
+    /// within the window can be swept at once for whatever had not vested at revocation.
+    pub fn sweep_revoked(env: Env, admin: Address, grant_id: u64) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        // Get vesting schedule
         let schedules_key = symbol_short!("sched");
-        let schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
             .storage()
             .persistent()
             .get(&schedules_key)
             .ok_or(VestingError::GrantNotFound)?;
 
-        let schedule = schedules
+        let mut schedule = schedules
             .get(grant_id)
             .ok_or(VestingError::GrantNotFound)?;
 
+        // Only revoked grants can be swept, and only once
+        if !schedule.revoked {
+            return Err(VestingError::InvalidSchedule);
+        }
+        let swept_key = (symbol_short!("swept"), grant_id);
+        if env.storage().persistent().has(&swept_key) {
+            return Err(VestingError::AlreadyClaimed);
+        }
+
+        // An unclaimed grant waits for its claim window to close; a claimed one is settled already
         let current_time = env.ledger().timestamp();
-        Self::calculate_vested_amount(&schedule, current_time)
+        if !schedule.claimed && Self::in_revoke_claim_window(&schedule, current_time) {
+            return Err(VestingError::ClaimWindowOpen);
+        }
+
+        // Whatever the beneficiary did not claim goes back to the admin
+        let remainder = schedule.amount - schedule.claimed_amount;
+        if remainder <= 0 {
+            return Err(VestingError::AlreadyClaimed);
+        }
+
+        let token_key = symbol_short!("token");
+        let token: Address = env
+            .storage()
+            .persistent()
+            .get(&token_key)
+            .ok_or(VestingError::Unauthorized)?;
+
+        let token_client = soroban_sdk::token::Client::new(&env, &token);
+        let balance = token_client.balance(&env.current_contract_address());
+        if balance - Self::get_bonus_pool(env.clone()) < remainder {
+            return Err(VestingError::InsufficientBalance);
+        }
+
+        // Settle the grant so it can be neither claimed nor swept again
+        schedule.claimed = true;
+        schedules.set(grant_id, schedule.clone());
+        env.storage().persistent().set(&schedules_key, &schedules);
+        env.storage().persistent().set(&swept_key, &true);
+
+        token_client.transfer(&env.current_contract_address(), &admin, &remainder);
+
+        // Emit sweep event
+        let sweep_event = SweepEvent {
+            grant_id,
+            beneficiary: schedule.beneficiary,
+            amount: remainder,
+            swept_at: current_time,
+            swept_by: admin,
+        };
+
+        env.events().publish((symbol_short!("sweep"),), sweep_event);
+
+        Ok(remainder)
     }
 
-    /// Internal helper: calculate vested amount based on schedule and current time
-    fn calculate_vested_amount(
-        schedule: &VestingSchedule,
-        current_time: u64,
-    ) -> Result<i128, VestingError> {
-        // If not started yet
-        if current_time < schedule.start_time {
-            return Ok(0);
+    /// Transfer an unclaimed grant to a new beneficiary (beneficiary only, transferable grants only)
+    pub fn transfer_grant(
+        env: Env,
+        grant_id: u64,
+        beneficiary: Address,
+        new_beneficiary: Address,
+    ) -> Result<(), VestingError> {
+        beneficiary.require_auth();
+
+        // Get vesting schedule
+        let schedules_key = symbol_short!("sched");
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+            .storage()
+            .persistent()
+            .get(&schedules_key)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        let mut schedule = schedules
+            .get(grant_id)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        // Verify beneficiary matches
+        if schedule.beneficiary != beneficiary {
+            return Err(VestingError::Unauthorized);
         }
 
-        // If cliff hasn't passed
-        if current_time < schedule.start_time + schedule.cliff {
-            return Ok(0);
+        // Locked grants stay with the original beneficiary
+        if !schedule.transferable {
+            return Err(VestingError::NotTransferable);
         }
 
-        // If fully vested
-        if current_time >= schedule.start_time + schedule.duration {
-            return Ok(schedule.amount);
+        if schedule.claimed {
+            return Err(VestingError::AlreadyClaimed);
         }
 
-        // Partial vesting (linear vesting after cliff)
-        let vested_duration = current_time - (schedule.start_time + schedule.cliff);
-        let remaining_duration = schedule.duration - schedule.cliff;
+        if schedule.revoked {
+            return Err(VestingError::Revoked);
+        }
 
-        if remaining_duration == 0 {
-            return Ok(schedule.amount);
+        Self::require_registered(&env, &new_beneficiary)?;
+
+        schedule.beneficiary = new_beneficiary.clone();
+        schedules.set(grant_id, schedule);
+        env.storage().persistent().set(&schedules_key, &schedules);
+        Self::unindex_grant(&env, &beneficiary, grant_id);
+        Self::index_grant(&env, &new_beneficiary, grant_id);
+
+        // Emit transfer event
+        let transfer_event = TransferEvent {
+            grant_id,
+            from: beneficiary,
+            to: new_beneficiary,
+            transferred_at: env.ledger().timestamp(),
+        };
+
+        env.events().publish((symbol_short!("transfer"),), transfer_event);
+
+        Ok(())
+    }
+
+    /// Add to a grant's amount without resetting it; the extra vests linearly over the remaining period (admin only)
+    pub fn top_up_grant(
+        env: Env,
+        admin: Address,
+        grant_id: u64,
+        additional_amount: i128,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        if additional_amount <= 0 {
+            return Err(VestingError::InvalidSchedule);
         }
 
-        // Use fixed-point arithmetic to avoid floating point
-        let vested_amount = (schedule.amount as u128 * vested_duration as u128)
-            / remaining_duration as u128;
+        let schedules_key = symbol_short!("sched");
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+            .storage()
+            .persistent()
+            .get(&schedules_key)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        let mut schedule = schedules
+            .get(grant_id)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        if schedule.claimed {
+            return Err(VestingError::AlreadyClaimed);
+        }
+        if schedule.revoked {
+            return Err(VestingError::Revoked);
+        }
+
+        // There must be vesting time left to spread the extra over
+        let current_time = env.ledger().timestamp();
+        if current_time >= schedule.start_time.saturating_add(schedule.duration) {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        schedule.amount += additional_amount;
+        schedules.set(grant_id, schedule.clone());
+        env.storage().persistent().set(&schedules_key, &schedules);
+
+        let top_ups_key = (symbol_short!("topups"), grant_id);
+        let mut top_ups: Vec<(u64, i128)> = env
+            .storage()
+            .persistent()
+            .get(&top_ups_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        top_ups.push_back((current_time, additional_amount));
+        env.storage().persistent().set(&top_ups_key, &top_ups);
+
+        env.events().publish(
+            (symbol_short!("topup"),),
+            (grant_id, additional_amount, schedule.amount),
+        );
+
+        Ok(schedule.amount)
+    }
+
+    /// Amend a grant's amount before its cliff; the extra vests as part of the original schedule (admin only)
+    pub fn top_up_vesting(
+        env: Env,
+        admin: Address,
+        grant_id: u64,
+        additional_amount: i128,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        if additional_amount <= 0 {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        let schedules_key = symbol_short!("sched");
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+            .storage()
+            .persistent()
+            .get(&schedules_key)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        let mut schedule = schedules
+            .get(grant_id)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        if schedule.claimed {
+            return Err(VestingError::AlreadyClaimed);
+        }
+        if schedule.revoked {
+            return Err(VestingError::Revoked);
+        }
+
+        // Only amendable while nothing has vested yet
+        let current_time = env.ledger().timestamp();
+        if current_time >= schedule.start_time.saturating_add(schedule.cliff) {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        schedule.amount += additional_amount;
+        schedules.set(grant_id, schedule.clone());
+        env.storage().persistent().set(&schedules_key, &schedules);
+
+        env.events().publish(
+            (symbol_short!("amend"),),
+            (grant_id, additional_amount, schedule.amount),
+        );
+
+        Ok(schedule.amount)
+    }
+
+    /// Confirm a grant created in acceptance mode so it can vest and be claimed (beneficiary only)
+    ///
+    /// Vesting runs from the later of `start_time` and the acceptance time: accepting moves the
+    /// grant's `start_time` forward, so nothing accrues while the grant was pending.
+    pub fn accept_grant(env: Env, grant_id: u64, beneficiary: Address) -> Result<(), VestingError> {
+        beneficiary.require_auth();
+
+        let schedules_key = symbol_short!("sched");
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+            .storage()
+            .persistent()
+            .get(&schedules_key)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        let mut schedule = schedules
+            .get(grant_id)
+            .ok_or(VestingError::GrantNotFound)?;
+        if schedule.beneficiary != beneficiary {
+            return Err(VestingError::Unauthorized);
+        }
+
+        let pending_key = (symbol_short!("pending"), grant_id);
+        if !env.storage().persistent().has(&pending_key) {
+            return Err(VestingError::NotPending);
+        }
+        env.storage().persistent().remove(&pending_key);
+
+        let accepted_at = env.ledger().timestamp();
+        if accepted_at > schedule.start_time {
+            schedule.start_time = accepted_at;
+            schedules.set(grant_id, schedule);
+            env.storage().persistent().set(&schedules_key, &schedules);
+        }
+
+        env.events().publish(
+            (symbol_short!("accept"),),
+            (grant_id, beneficiary, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Check whether a grant has been accepted (always true for grants made outside acceptance mode)
+    pub fn is_grant_accepted(env: Env, grant_id: u64) -> bool {
+        let pending_key = (symbol_short!("pending"), grant_id);
+        !env.storage().persistent().has(&pending_key)
+    }
+
+    /// Hand governance over to a new address (current governance only)
+    pub fn transfer_governance(
+        env: Env,
+        governance: Address,
+        new_governance: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_governance(&env, &governance)?;
+
+        let gov_key = symbol_short!("gov");
+        env.storage().persistent().set(&gov_key, &new_governance);
+
+        Ok(())
+    }
+
+    /// Set the smallest `revoke_delay` that revoke accepts (governance only)
+    pub fn set_min_revoke_delay(
+        env: Env,
+        governance: Address,
+        min_revoke_delay: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_governance(&env, &governance)?;
+
+        let min_delay_key = symbol_short!("min_rvk");
+        env.storage().persistent().set(&min_delay_key, &min_revoke_delay);
+
+        Ok(())
+    }
+
+    /// Get the minimum revocation timelock in seconds
+    pub fn get_min_revoke_delay(env: Env) -> u64 {
+        let min_delay_key = symbol_short!("min_rvk");
+        env.storage()
+            .persistent()
+            .get(&min_delay_key)
+            .unwrap_or(DEFAULT_MIN_REVOKE_DELAY)
+    }
+
+    /// Propose a code upgrade that may execute no earlier than `earliest_execute` (governance only)
+    ///
+    /// `earliest_execute` must be at least `MIN_UPGRADE_DELAY` seconds from now.
+    pub fn propose_upgrade(
+        env: Env,
+        governance: Address,
+        new_wasm_hash: BytesN<32>,
+        earliest_execute: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_governance(&env, &governance)?;
+
+        if earliest_execute < env.ledger().timestamp().saturating_add(MIN_UPGRADE_DELAY) {
+            return Err(VestingError::InvalidTimelock);
+        }
+
+        let upgrade_key = symbol_short!("upgrade");
+        env.storage()
+            .persistent()
+            .set(&upgrade_key, &(new_wasm_hash.clone(), earliest_execute));
+
+        env.events().publish(
+            (symbol_short!("upg_prop"),),
+            (new_wasm_hash, earliest_execute),
+        );
+
+        Ok(())
+    }
+
+    /// Apply the proposed upgrade once its timelock has elapsed (governance only)
+    pub fn execute_upgrade(env: Env, governance: Address) -> Result<(), VestingError> {
+        Self::require_governance(&env, &governance)?;
+
+        let upgrade_key = symbol_short!("upgrade");
+        let (new_wasm_hash, earliest_execute): (BytesN<32>, u64) = env
+            .storage()
+            .persistent()
+            .get(&upgrade_key)
+            .ok_or(VestingError::NoUpgradePending)?;
+
+        if env.ledger().timestamp() < earliest_execute {
+            return Err(VestingError::UpgradeTimelockActive);
+        }
+
+        env.storage().persistent().remove(&upgrade_key);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events().publish((symbol_short!("upg_exec"),), new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Get the proposed upgrade hash and its earliest execution time, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<(BytesN<32>, u64)> {
+        let upgrade_key = symbol_short!("upgrade");
+        env.storage().persistent().get(&upgrade_key)
+    }
+
+    /// Set the allowed range for grant durations (admin only)
+    pub fn set_duration_bounds(
+        env: Env,
+        admin: Address,
+        min_duration: u64,
+        max_duration: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        if min_duration > max_duration {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        let min_key = symbol_short!("min_dur");
+        let max_key = symbol_short!("max_dur");
+        env.storage().persistent().set(&min_key, &min_duration);
+        env.storage().persistent().set(&max_key, &max_duration);
+
+        Ok(())
+    }
+
+    /// Get the allowed (min, max) grant duration; defaults to the full u64 range
+    pub fn get_duration_bounds(env: Env) -> (u64, u64) {
+        let min_key = symbol_short!("min_dur");
+        let max_key = symbol_short!("max_dur");
+
+        let min_duration = env
+            .storage()
+            .persistent()
+            .get(&min_key)
+            .unwrap_or(0);
+        let max_duration = env
+            .storage()
+            .persistent()
+            .get(&max_key)
+            .unwrap_or(u64::MAX);
+
+        (min_duration, max_duration)
+    }
+
+    /// Set how long a beneficiary may still claim after revocation (admin only, 0 = no window)
+    pub fn set_revoke_claim_window(
+        env: Env,
+        admin: Address,
+        window_seconds: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let window_key = symbol_short!("rv_win");
+        env.storage().persistent().set(&window_key, &window_seconds);
+
+        Ok(())
+    }
+
+    /// Get the post-revocation claim window in seconds (0 if unset)
+    pub fn get_revoke_claim_window(env: Env) -> u64 {
+        let window_key = symbol_short!("rv_win");
+        env.storage()
+            .persistent()
+            .get(&window_key)
+            .unwrap_or(0)
+    }
+
+    /// Make revoke schedule a revocation that takes effect after `notice_seconds` (admin only, 0 = immediate)
+    pub fn set_revoke_notice_period(
+        env: Env,
+        admin: Address,
+        notice_seconds: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let notice_key = symbol_short!("rv_note");
+        env.storage().persistent().set(&notice_key, &notice_seconds);
+
+        Ok(())
+    }
+
+    /// Get the revocation notice period in seconds (0 if unset)
+    pub fn get_revoke_notice_period(env: Env) -> u64 {
+        let notice_key = symbol_short!("rv_note");
+        env.storage()
+            .persistent()
+            .get(&notice_key)
+            .unwrap_or(0)
+    }
+
+    /// Set the fee (in bps of the vested amount) withheld on claim and where it is sent (admin only)
+    pub fn set_claim_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        fee_recipient: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        if fee_bps > 10000 {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        let fee_bps_key = symbol_short!("fee_bps");
+        let fee_recipient_key = symbol_short!("fee_to");
+        env.storage().persistent().set(&fee_bps_key, &fee_bps);
+        env.storage().persistent().set(&fee_recipient_key, &fee_recipient);
+
+        Ok(())
+    }
+
+    /// Get the claim fee in bps (0 if unset)
+    pub fn get_claim_fee_bps(env: Env) -> u32 {
+        let fee_bps_key = symbol_short!("fee_bps");
+        env.storage()
+            .persistent()
+            .get(&fee_bps_key)
+            .unwrap_or(0)
+    }
+
+    /// Set the academy-rewards contract whose badge discounts reduce claim fees (admin only)
+    pub fn set_academy_contract(
+        env: Env,
+        admin: Address,
+        academy: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let academy_key = symbol_short!("academy");
+        env.storage().persistent().set(&academy_key, &academy);
+
+        Ok(())
+    }
+
+    /// Set (or clear) the identity registry that grant and transfer beneficiaries must be registered in (admin only)
+    pub fn set_identity_registry(
+        env: Env,
+        admin: Address,
+        registry: Option<Address>,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let registry_key = symbol_short!("id_reg");
+        match registry {
+            Some(registry) => env.storage().persistent().set(&registry_key, &registry),
+            None => env.storage().persistent().remove(&registry_key),
+        }
+
+        Ok(())
+    }
+
+    /// Get the identity registry beneficiaries are checked against, if any
+    pub fn get_identity_registry(env: Env) -> Option<Address> {
+        let registry_key = symbol_short!("id_reg");
+        env.storage().persistent().get(&registry_key)
+    }
+
+    /// Require beneficiaries to accept new grants before they vest (admin only)
+    pub fn set_require_acceptance(
+        env: Env,
+        admin: Address,
+        required: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let acceptance_key = symbol_short!("req_acc");
+        env.storage().persistent().set(&acceptance_key, &required);
+
+        Ok(())
+    }
+
+    /// Check whether new grants start unconfirmed
+    pub fn get_require_acceptance(env: Env) -> bool {
+        let acceptance_key = symbol_short!("req_acc");
+        env.storage()
+            .persistent()
+            .get(&acceptance_key)
+            .unwrap_or(false)
+    }
+
+    /// Set the bonus (in bps of the grant amount) paid to beneficiaries who wait for full vesting (admin only)
+    pub fn set_patience_bonus(env: Env, admin: Address, bonus_bps: u32) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        if bonus_bps > 10000 {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        let bonus_bps_key = symbol_short!("bonus_bps");
+        env.storage().persistent().set(&bonus_bps_key, &bonus_bps);
+
+        Ok(())
+    }
+
+    /// Get the patience bonus in bps (0 if unset)
+    pub fn get_patience_bonus(env: Env) -> u32 {
+        let bonus_bps_key = symbol_short!("bonus_bps");
+        env.storage()
+            .persistent()
+            .get(&bonus_bps_key)
+            .unwrap_or(0)
+    }
+
+    /// Add reward tokens to the patience bonus pool (anyone may fund it)
+    pub fn fund_bonus_pool(env: Env, funder: Address, amount: i128) -> Result<(), VestingError> {
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        let token_key = symbol_short!("token");
+        let token: Address = env
+            .storage()
+            .persistent()
+            .get(&token_key)
+            .ok_or(VestingError::Unauthorized)?;
+        soroban_sdk::token::Client::new(&env, &token).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let pool_key = symbol_short!("bonus");
+        let pool = Self::get_bonus_pool(env.clone());
+        env.storage().persistent().set(&pool_key, &(pool + amount));
+
+        Ok(())
+    }
+
+    /// Get the tokens left in the patience bonus pool
+    pub fn get_bonus_pool(env: Env) -> i128 {
+        let pool_key = symbol_short!("bonus");
+        env.storage()
+            .persistent()
+            .get(&pool_key)
+            .unwrap_or(0)
+    }
+
+    /// Check whether a grant was claimed before it fully vested
+    pub fn was_claimed_early(env: Env, grant_id: u64) -> bool {
+        let early_key = (symbol_short!("early"), grant_id);
+        env.storage()
+            .persistent()
+            .get(&early_key)
+            .unwrap_or(false)
+    }
+
+    /// Also halt claims whenever the shared emergency registry reports a global halt (admin only)
+    pub fn set_emergency_registry(
+        env: Env,
+        admin: Address,
+        registry: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let registry_key = symbol_short!("emrg_reg");
+        env.storage().persistent().set(&registry_key, &registry);
+        env.storage().temporary().remove(&symbol_short!("halt_c"));
+
+        Ok(())
+    }
+
+    /// Get the emergency registry claims are checked against, if any
+    pub fn get_emergency_registry(env: Env) -> Option<Address> {
+        let registry_key = symbol_short!("emrg_reg");
+        env.storage().persistent().get(&registry_key)
+    }
+
+    /// Pause or unpause claims (admin only); grants and revocations remain available
+    pub fn set_paused(env: Env, admin: Address, paused: bool) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+
+        let paused_key = symbol_short!("paused");
+        env.storage().persistent().set(&paused_key, &paused);
+
+        Ok(())
+    }
+
+    /// Pause claims on a single grant (admin only)
+    pub fn pause_grant(env: Env, admin: Address, grant_id: u64) -> Result<(), VestingError> {
+        Self::set_grant_paused(&env, &admin, grant_id, true)
+    }
+
+    /// Resume claims on a single grant (admin only)
+    pub fn unpause_grant(env: Env, admin: Address, grant_id: u64) -> Result<(), VestingError> {
+        Self::set_grant_paused(&env, &admin, grant_id, false)
+    }
+
+    /// Check whether claims on a single grant are paused
+    pub fn is_grant_paused(env: Env, grant_id: u64) -> bool {
+        let grant_paused_key = (symbol_short!("gpaused"), grant_id);
+        env.storage()
+            .persistent()
+            .get(&grant_paused_key)
+            .unwrap_or(false)
+    }
+
+    /// Check whether claims are paused
+    pub fn is_paused(env: Env) -> bool {
+        let paused_key = symbol_short!("paused");
+        env.storage()
+            .persistent()
+            .get(&paused_key)
+            .unwrap_or(false)
+    }
+
+    /// Query vesting schedule details
+    pub fn get_vesting(env: Env, grant_id: u64) -> Result<VestingSchedule, VestingError> {
+        let schedules_key = symbol_short!("sched");
+        let schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+            .storage()
+            .persistent()
+            .get(&schedules_key)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        schedules
+            .get(grant_id)
+            .ok_or(VestingError::GrantNotFound)
+    }
+
+    /// Calculate vested amount at current time
+    pub fn get_vested_amount(env: Env, grant_id: u64) -> Result<i128, VestingError> {
+        let schedules_key = symbol_short!("sched");
+        let schedules: soroban_sdk::Map<u64, VestingSchedule> = env
+            .storage()
+            .persistent()
+            .get(&schedules_key)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        let schedule = schedules
+            .get(grant_id)
+            .ok_or(VestingError::GrantNotFound)?;
+
+        // Pending grants are locked; accepting them restarts vesting from the acceptance time
+        if !Self::is_grant_accepted(env.clone(), grant_id) {
+            return Ok(0);
+        }
+
+        let current_time = env.ledger().timestamp();
+        Self::vested_with_top_ups(&env, grant_id, &schedule, current_time)
+    }
+
+    /// Internal helper: verify the caller is the stored admin
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), VestingError> {
+        admin.require_auth();
+
+        let admin_key = symbol_short!("admin");
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(VestingError::Unauthorized)?;
+
+        if *admin != stored_admin {
+            return Err(VestingError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    /// Internal helper: set the per-grant pause flag for an existing grant
+    fn set_grant_paused(
+        env: &Env,
+        admin: &Address,
+        grant_id: u64,
+        paused: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(env, admin)?;
+        Self::get_vesting(env.clone(), grant_id)?;
+
+        let grant_paused_key = (symbol_short!("gpaused"), grant_id);
+        env.storage().persistent().set(&grant_paused_key, &paused);
+
+        Ok(())
+    }
+
+    /// Internal helper: deduct the patience bonus for a grant from the pool, capped at what is left
+    fn take_patience_bonus(env: &Env, grant_amount: i128) -> i128 {
+        let bonus_bps = Self::get_patience_bonus(env.clone());
+        let pool = Self::get_bonus_pool(env.clone());
+
+        let bonus = (grant_amount * bonus_bps as i128 / 10000).min(pool);
+        if bonus > 0 {
+            let pool_key = symbol_short!("bonus");
+            env.storage().persistent().set(&pool_key, &(pool - bonus));
+        }
+
+        bonus
+    }
+
+    /// Internal helper: verify the caller is the stored governance address
+    fn require_governance(env: &Env, governance: &Address) -> Result<(), VestingError> {
+        governance.require_auth();
+
+        let gov_key = symbol_short!("gov");
+        let stored_governance: Address = env
+            .storage()
+            .persistent()
+            .get(&gov_key)
+            .ok_or(VestingError::Unauthorized)?;
+
+        if *governance != stored_governance {
+            return Err(VestingError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    /// Internal helper: whether a revoked grant is still inside the claim window it was revoked with
+    fn in_revoke_claim_window(schedule: &VestingSchedule, current_time: u64) -> bool {
+        schedule.revoke_window > 0
+            && current_time <= schedule.revoke_time.saturating_add(schedule.revoke_window)
+    }
+
+    /// Internal helper: claim fee for `amount` after the beneficiary's badge discount
+    fn claim_fee(env: &Env, beneficiary: &Address, amount: i128) -> i128 {
+        let fee_bps = Self::get_claim_fee_bps(env.clone());
+        if fee_bps == 0 {
+            return 0;
+        }
+
+        let fee = amount * fee_bps as i128 / 10000;
+        let discount_bps = Self::academy_discount(env, beneficiary).min(10000);
+
+        fee - fee * discount_bps as i128 / 10000
+    }
+
+    /// Internal helper: badge discount (bps) from the academy contract, 0 if none is
+    /// configured or the lookup fails, so a broken academy never blocks claims
+    fn academy_discount(env: &Env, beneficiary: &Address) -> u32 {
+        let academy_key = symbol_short!("academy");
+        let academy: Option<Address> = env.storage().persistent().get(&academy_key);
+
+        match academy {
+            Some(academy) => match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+                &academy,
+                &Symbol::new(env, "get_user_discount"),
+                vec![env, beneficiary.into_val(env)],
+            ) {
+                Ok(Ok(discount)) => discount,
+                _ => 0,
+            },
+            None => 0,
+        }
+    }
+
+    /// Internal helper: add a grant to its beneficiary's index
+    fn index_grant(env: &Env, beneficiary: &Address, grant_id: u64) {
+        let mut grant_ids = Self::get_beneficiary_grants(env.clone(), beneficiary.clone());
+        grant_ids.push_back(grant_id);
+        let index_key = (symbol_short!("by_ben"), beneficiary.clone());
+        env.storage().persistent().set(&index_key, &grant_ids);
+    }
+
+    /// Internal helper: drop a grant from its former beneficiary's index
+    fn unindex_grant(env: &Env, beneficiary: &Address, grant_id: u64) {
+        let mut grant_ids = Self::get_beneficiary_grants(env.clone(), beneficiary.clone());
+        if let Some(position) = grant_ids.first_index_of(grant_id) {
+            grant_ids.remove(position);
+        }
+        let index_key = (symbol_short!("by_ben"), beneficiary.clone());
+        env.storage().persistent().set(&index_key, &grant_ids);
+    }
+
+    /// Internal helper: whether the configured emergency registry reports a global halt,
+    /// queried at most once per ledger
+    fn is_globally_halted(env: &Env) -> bool {
+        let registry = match Self::get_emergency_registry(env.clone()) {
+            Some(registry) => registry,
+            None => return false,
+        };
+
+        let cache_key = symbol_short!("halt_c");
+        if let Some((sequence, halted)) = env.storage().temporary().get::<_, (u32, bool)>(&cache_key) {
+            if sequence == env.ledger().sequence() {
+                return halted;
+            }
+        }
+
+        let halted: bool = env.invoke_contract(&registry, &Symbol::new(env, "is_halted"), Vec::new(env));
+        env.storage().temporary().set(&cache_key, &(env.ledger().sequence(), halted));
+        halted
+    }
+
+    /// Internal helper: require `beneficiary` to pass the identity registry's `is_registered` check, if one is configured
+    fn require_registered(env: &Env, beneficiary: &Address) -> Result<(), VestingError> {
+        let registry_key = symbol_short!("id_reg");
+        let registry: Option<Address> = env.storage().persistent().get(&registry_key);
+
+        if let Some(registry) = registry {
+            let registered: bool = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "is_registered"),
+                vec![env, beneficiary.into_val(env)],
+            );
+            if !registered {
+                return Err(VestingError::BeneficiaryNotRegistered);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Internal helper: vested amount including top-ups, each vesting linearly from when it was added
+    fn vested_with_top_ups(
+        env: &Env,
+        grant_id: u64,
+        schedule: &VestingSchedule,
+        current_time: u64,
+    ) -> Result<i128, VestingError> {
+        let top_ups_key = (symbol_short!("topups"), grant_id);
+        let top_ups: Vec<(u64, i128)> = env
+            .storage()
+            .persistent()
+            .get(&top_ups_key)
+            .unwrap_or_else(|| Vec::new(env));
+
+        let end_time = schedule.start_time.saturating_add(schedule.duration);
+        let cliff_passed = current_time >= schedule.start_time.saturating_add(schedule.cliff);
+
+        let mut original = schedule.clone();
+        let mut top_up_vested: i128 = 0;
+        for (added_at, amount) in top_ups.iter() {
+            original.amount -= amount;
+
+            let from = added_at.max(schedule.start_time);
+            if current_time >= end_time {
+                top_up_vested += amount;
+            } else if cliff_passed && current_time > from {
+                top_up_vested += (amount as u128 * (current_time - from) as u128
+                    / (end_time - from) as u128) as i128;
+            }
+        }
+
+        Ok(Self::calculate_vested_amount(&original, current_time)? + top_up_vested)
+    }
+
+    /// Internal helper: calculate vested amount based on schedule and current time
+    fn calculate_vested_amount(
+        schedule: &VestingSchedule,
+        current_time: u64,
+    ) -> Result<i128, VestingError> {
+        // If not started yet
+        if current_time < schedule.start_time {
+            return Ok(0);
+        }
+
+        // If cliff hasn't passed
+        if current_time < schedule.start_time + schedule.cliff {
+            return Ok(0);
+        }
+
+        // If fully vested
+        if current_time >= schedule.start_time + schedule.duration {
+            return Ok(schedule.amount);
+        }
+
+        // Lump sum unlocked at the cliff; the remainder vests linearly
+        let cliff_amount = (schedule.amount as u128 * schedule.cliff_unlock_bps as u128 / 10000) as i128;
+        let linear_amount = schedule.amount - cliff_amount;
+
+        // Partial vesting (linear vesting after cliff)
+        let vested_duration = current_time - (schedule.start_time + schedule.cliff);
+        let remaining_duration = schedule.duration - schedule.cliff;
+
+        if remaining_duration == 0 {
+            return Ok(schedule.amount);
+        }
+
+        // Use fixed-point arithmetic to avoid floating point
+        let vested_amount = (linear_amount as u128 * vested_duration as u128)
+            / remaining_duration as u128;
+
+        Ok(cliff_amount + vested_amount as i128)
+    }
+
+    /// Compute what a grant with these parameters would have vested at `at_time`, without storing one
+    pub fn preview_vested(
+        env: Env,
+        amount: i128,
+        start_time: u64,
+        cliff: u64,
+        duration: u64,
+        cliff_unlock_bps: u32,
+        at_time: u64,
+    ) -> Result<i128, VestingError> {
+        if amount <= 0 || cliff > duration || cliff_unlock_bps > 10000 {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        let schedule = VestingSchedule {
+            beneficiary: env.current_contract_address(),
+            amount,
+            start_time,
+            cliff,
+            duration,
+            cliff_unlock_bps,
+            transferable: false,
+            claimed: false,
+            revoked: false,
+            revoke_time: 0,
+            revoke_window: 0,
+            claimed_amount: 0,
+        };
 
-        Ok(vested_amount as i128)
+        Self::calculate_vested_amount(&schedule, at_time)
     }
 
     /// Get contract information
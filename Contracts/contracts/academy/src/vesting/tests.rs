@@ -1,5 +1,5 @@
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Address, Env};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, testutils::Events as _, testutils::Ledger as _, token, Address, Env, TryIntoVal};
 
     fn setup_env() -> (Env, Address, Address, Address, Address) {
         let env = Env::default();
@@ -22,6 +22,10 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         (token_id, token_client, token_admin)
     }
 
+    fn terms(cliff: u64, cliff_unlock_bps: u32, transferable: bool) -> GrantTerms {
+        GrantTerms { cliff, cliff_unlock_bps, transferable }
+    }
+
     #[test]
     fn test_init_and_get_info() {
         let (env, admin, _beneficiary, governance, contract_id) = setup_env();
@@ -36,6 +40,22 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         assert_eq!(stored_gov, governance);
     }
 
+    #[test]
+    fn test_init_emits_initialized_event() {
+        let (env, admin, _beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        let (event_contract, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(event_contract, contract_id);
+        let topic: Symbol = topics.first().unwrap().try_into_val(&env).unwrap();
+        assert_eq!(topic, Symbol::new(&env, "initialized"));
+        let data: (Address, Address, Address) = data.try_into_val(&env).unwrap();
+        assert_eq!(data, (admin, token_id, governance));
+    }
+
     #[test]
     fn test_init_twice_fails() {
         let (env, admin, _beneficiary, governance, contract_id) = setup_env();
@@ -63,7 +83,7 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
 
         client.init(&admin, &token_id, &governance);
 
-        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &100, &1000);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &1000, &terms(100, 0, true));
         assert_eq!(grant_id, 1);
 
         let schedule = client.get_vesting(&grant_id);
@@ -75,6 +95,39 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         assert!(!schedule.revoked);
     }
 
+    #[test]
+    fn test_backdated_grant_is_flagged_in_event() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        // Ledger time is 1000, so a start_time of 500 is in the past
+        let backdated_id = client.grant_vesting(&admin, &beneficiary, &1000, &500, &1000, &terms(0, 0, true));
+        let future_id = client.grant_vesting(&admin, &beneficiary, &1000, &2000, &1000, &terms(0, 0, true));
+
+        let mut grant_events: soroban_sdk::Vec<GrantEvent> = soroban_sdk::Vec::new(&env);
+        let mut backdate_events = 0;
+        for (_, topics, data) in env.events().all().iter() {
+            let topic: Symbol = topics.first().unwrap().try_into_val(&env).unwrap();
+            if topic == symbol_short!("grant") {
+                grant_events.push_back(data.try_into_val(&env).unwrap());
+            } else if topic == symbol_short!("backdate") {
+                backdate_events += 1;
+            }
+        }
+
+        assert_eq!(grant_events.len(), 2);
+        let backdated = grant_events.get(0).unwrap();
+        assert_eq!(backdated.grant_id, backdated_id);
+        assert!(backdated.is_backdated);
+        let future = grant_events.get(1).unwrap();
+        assert_eq!(future.grant_id, future_id);
+        assert!(!future.is_backdated);
+        assert_eq!(backdate_events, 1);
+    }
+
     #[test]
     fn test_grant_vesting_invalid_schedule() {
         let (env, admin, beneficiary, governance, contract_id) = setup_env();
@@ -84,14 +137,102 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         client.init(&admin, &token_id, &governance);
 
     let negative = env.as_contract(&contract_id, || {
-        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), -1, 0, 10, 100)
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), -1, 0, 100, terms(10, 0, true))
     });
     assert_eq!(negative, Err(VestingError::InvalidSchedule));
 
     let bad_cliff = env.as_contract(&contract_id, || {
-        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 1000, 0, 200, 100)
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 1000, 0, 100, terms(200, 0, true))
     });
     assert_eq!(bad_cliff, Err(VestingError::InvalidSchedule));
+
+    let bad_cliff_unlock = env.as_contract(&contract_id, || {
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 1000, 0, 100, terms(10, 10001, true))
+    });
+    assert_eq!(bad_cliff_unlock, Err(VestingError::InvalidSchedule));
+    }
+
+    #[test]
+    fn test_cliff_unlock_vests_lump_sum_at_cliff() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        // 20% unlocks at the cliff, remaining 80% vests linearly over the rest
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &1000, &terms(100, 2000, true));
+        assert_eq!(client.get_vesting(&grant_id).cliff_unlock_bps, 2000);
+
+        set_timestamp(&env, 99);
+        assert_eq!(client.get_vested_amount(&grant_id), 0);
+
+        set_timestamp(&env, 100);
+        assert_eq!(client.get_vested_amount(&grant_id), 200);
+
+        set_timestamp(&env, 550);
+        assert_eq!(client.get_vested_amount(&grant_id), 600);
+
+        set_timestamp(&env, 1000);
+        assert_eq!(client.get_vested_amount(&grant_id), 1000);
+
+        token_admin.mint(&contract_id, &1000);
+        let claimed = client.claim(&grant_id, &beneficiary, &None);
+        assert_eq!(claimed, 1000);
+        assert_eq!(token_client.balance(&beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_preview_vested_matches_grant() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &200, &1000, &terms(100, 2000, true));
+
+        for at_time in [0u64, 250, 299, 300, 750, 1199, 1200, 5000] {
+            set_timestamp(&env, at_time);
+            assert_eq!(
+                client.preview_vested(&1000, &200, &100, &1000, &2000, &at_time),
+                client.get_vested_amount(&grant_id)
+            );
+        }
+
+        let invalid = env.as_contract(&contract_id, || {
+            AcademyVestingContract::preview_vested(env.clone(), 1000, 0, 200, 100, 0, 50)
+        });
+        assert_eq!(invalid, Err(VestingError::InvalidSchedule));
+    }
+
+    #[test]
+    fn test_grant_vesting_duration_bounds() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        assert_eq!(client.get_duration_bounds(), (0, u64::MAX));
+
+        let inverted = env.as_contract(&contract_id, || {
+            AcademyVestingContract::set_duration_bounds(env.clone(), admin.clone(), 500, 100)
+        });
+        assert_eq!(inverted, Err(VestingError::InvalidSchedule));
+
+        client.set_duration_bounds(&admin, &100, &10_000);
+
+        let too_short = env.as_contract(&contract_id, || {
+            AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 1000, 0, 99, terms(0, 0, true))
+        });
+        assert_eq!(too_short, Err(VestingError::InvalidSchedule));
+
+        let too_long = env.as_contract(&contract_id, || {
+            AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 1000, 0, 10_001, terms(0, 0, true))
+        });
+        assert_eq!(too_long, Err(VestingError::InvalidSchedule));
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &5000, &terms(0, 0, true));
+        assert_eq!(client.get_vesting(&grant_id).duration, 5000);
     }
 
     #[test]
@@ -103,7 +244,7 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
 
         client.init(&admin, &token_id, &governance);
 
-        let result = client.try_grant_vesting(&non_admin, &beneficiary, &1000, &0, &10, &100);
+        let result = client.try_grant_vesting(&non_admin, &beneficiary, &1000, &0, &100, &terms(10, 0, true));
         assert_eq!(result, Err(Ok(VestingError::Unauthorized)));
     }
 
@@ -116,13 +257,13 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         client.init(&admin, &token_id, &governance);
 
     let grant_id = env.as_contract(&contract_id, || {
-        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 0, 100)
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 100, terms(0, 0, true))
     }).unwrap();
 
         token_admin.mint(&contract_id, &500);
         set_timestamp(&env, 200);
 
-        let claimed = client.claim(&grant_id, &beneficiary);
+        let claimed = client.claim(&grant_id, &beneficiary, &None);
         assert_eq!(claimed, 500);
         assert_eq!(token_client.balance(&contract_id), 0);
         assert_eq!(token_client.balance(&beneficiary), 500);
@@ -131,6 +272,335 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         assert!(schedule.claimed);
     }
 
+    #[test]
+    fn test_pause_blocks_claim_until_unpaused() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let non_admin = Address::generate(&env);
+
+        client.init(&admin, &token_id, &governance);
+        assert!(!client.is_paused());
+
+        let unauthorized = env.as_contract(&contract_id, || {
+            AcademyVestingContract::set_paused(env.clone(), non_admin.clone(), true)
+        });
+        assert_eq!(unauthorized, Err(VestingError::Unauthorized));
+
+        client.set_paused(&admin, &true);
+        assert!(client.is_paused());
+
+        // Admin can still grant while paused
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        token_admin.mint(&contract_id, &500);
+        set_timestamp(&env, 200);
+
+        let paused = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
+        });
+        assert_eq!(paused, Err(VestingError::Paused));
+
+        client.set_paused(&admin, &false);
+        let claimed = client.claim(&grant_id, &beneficiary, &None);
+        assert_eq!(claimed, 500);
+        assert_eq!(token_client.balance(&beneficiary), 500);
+    }
+
+    #[test]
+    fn test_global_halt_blocks_claim_until_lifted() {
+        use emergency_registry::{EmergencyRegistryContract, EmergencyRegistryContractClient};
+
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        let registry_id = env.register_contract(None, EmergencyRegistryContract);
+        let registry = EmergencyRegistryContractClient::new(&env, &registry_id);
+        registry.initialize(&admin);
+        client.set_emergency_registry(&admin, &registry_id);
+        assert_eq!(client.get_emergency_registry(), Some(registry_id));
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        token_admin.mint(&contract_id, &500);
+        set_timestamp(&env, 200);
+
+        registry.set_global_halt(&admin, &true);
+        let halted = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
+        });
+        assert_eq!(halted, Err(VestingError::GloballyHalted));
+        assert!(!client.is_paused());
+
+        // The halt flag is cached per ledger, so lifting it takes effect from the next one
+        registry.set_global_halt(&admin, &false);
+        let still_halted = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
+        });
+        assert_eq!(still_halted, Err(VestingError::GloballyHalted));
+
+        env.ledger().with_mut(|li| li.sequence_number += 1);
+        assert_eq!(client.claim(&grant_id, &beneficiary, &None), 500);
+        assert_eq!(token_client.balance(&beneficiary), 500);
+    }
+
+    #[test]
+    fn test_pause_single_grant_leaves_others_claimable() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        token_admin.mint(&contract_id, &1000);
+
+        let disputed_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        let other_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+
+        client.pause_grant(&admin, &disputed_id);
+        assert!(client.is_grant_paused(&disputed_id));
+        assert!(!client.is_grant_paused(&other_id));
+        set_timestamp(&env, 200);
+
+        let paused = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), disputed_id, beneficiary.clone(), None)
+        });
+        assert_eq!(paused, Err(VestingError::GrantPaused));
+
+        assert_eq!(client.claim(&other_id, &beneficiary, &None), 500);
+
+        client.unpause_grant(&admin, &disputed_id);
+        assert_eq!(client.claim(&disputed_id, &beneficiary, &None), 500);
+        assert_eq!(token_client.balance(&beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_acceptance_mode_locks_grant_until_accepted() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_require_acceptance(&admin, &true);
+        token_admin.mint(&contract_id, &500);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        assert!(!client.is_grant_accepted(&grant_id));
+        set_timestamp(&env, 200);
+        assert_eq!(client.get_vested_amount(&grant_id), 0);
+
+        let unaccepted = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
+        });
+        assert_eq!(unaccepted, Err(VestingError::NotAccepted));
+
+        // Only the named beneficiary can confirm the grant
+        let stranger = Address::generate(&env);
+        let result = client.try_accept_grant(&grant_id, &stranger);
+        assert_eq!(result, Err(Ok(VestingError::Unauthorized)));
+
+        client.accept_grant(&grant_id, &beneficiary);
+        assert!(client.is_grant_accepted(&grant_id));
+
+        // Vesting starts at acceptance rather than accruing retroactively
+        assert_eq!(client.get_vesting(&grant_id).start_time, 200);
+        assert_eq!(client.get_vested_amount(&grant_id), 0);
+        set_timestamp(&env, 250);
+        assert_eq!(client.get_vested_amount(&grant_id), 250);
+
+        set_timestamp(&env, 300);
+        assert_eq!(client.claim(&grant_id, &beneficiary, &None), 500);
+        assert_eq!(token_client.balance(&beneficiary), 500);
+
+        let again = env.as_contract(&contract_id, || {
+            AcademyVestingContract::accept_grant(env.clone(), grant_id, beneficiary.clone())
+        });
+        assert_eq!(again, Err(VestingError::NotPending));
+    }
+
+    #[test]
+    fn test_patience_bonus_paid_only_after_full_vesting() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_patience_bonus(&admin, &1000);
+        token_admin.mint(&admin, &100);
+        client.fund_bonus_pool(&admin, &100);
+        token_admin.mint(&contract_id, &1000);
+
+        let early_claimer = Address::generate(&env);
+        let patient_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        let early_id = client.grant_vesting(&admin, &early_claimer, &500, &0, &100, &terms(0, 0, true));
+
+        // Claiming halfway through vesting pays only the linear amount
+        set_timestamp(&env, 50);
+        assert_eq!(client.claim(&early_id, &early_claimer, &None), 250);
+        assert!(client.was_claimed_early(&early_id));
+        assert_eq!(client.get_bonus_pool(), 100);
+
+        // Waiting for full vesting adds 10% of the grant from the pool
+        set_timestamp(&env, 100);
+        assert_eq!(client.claim(&patient_id, &beneficiary, &None), 550);
+        assert!(!client.was_claimed_early(&patient_id));
+        assert_eq!(token_client.balance(&beneficiary), 550);
+        assert_eq!(client.get_bonus_pool(), 50);
+    }
+
+    #[test]
+    fn test_bonus_pool_is_not_spent_as_principal() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_patience_bonus(&admin, &1000);
+        token_admin.mint(&admin, &100);
+        client.fund_bonus_pool(&admin, &100);
+
+        // Principal is underfunded by 50; the pool would otherwise cover the gap
+        token_admin.mint(&contract_id, &450);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+
+        set_timestamp(&env, 100);
+        let underfunded = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
+        });
+        assert_eq!(underfunded, Err(VestingError::InsufficientBalance));
+        assert_eq!(client.get_bonus_pool(), 100);
+
+        // Sweeping a revoked grant is held to the same rule
+        set_timestamp(&env, 5000);
+        client.revoke(&grant_id, &governance, &3600);
+        let sweep = env.as_contract(&contract_id, || {
+            AcademyVestingContract::sweep_revoked(env.clone(), admin.clone(), grant_id)
+        });
+        assert_eq!(sweep, Err(VestingError::InsufficientBalance));
+
+        token_admin.mint(&contract_id, &50);
+        assert_eq!(client.sweep_revoked(&admin, &grant_id), 500);
+        assert_eq!(token_client.balance(&contract_id), 100);
+        assert_eq!(client.get_bonus_pool(), 100);
+    }
+
+    #[test]
+    fn test_top_up_grant_vests_over_remaining_period() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &100, &terms(0, 0, true));
+
+        set_timestamp(&env, 50);
+        assert_eq!(client.get_vested_amount(&grant_id), 500);
+
+        assert_eq!(client.top_up_grant(&admin, &grant_id, &500), 1500);
+        assert_eq!(client.get_vesting(&grant_id).amount, 1500);
+
+        // The top-up is not credited retroactively
+        assert_eq!(client.get_vested_amount(&grant_id), 500);
+
+        // Halfway through the remaining period: 750 original + 250 of the top-up
+        set_timestamp(&env, 75);
+        assert_eq!(client.get_vested_amount(&grant_id), 1000);
+
+        set_timestamp(&env, 100);
+        assert_eq!(client.get_vested_amount(&grant_id), 1500);
+
+        // Nothing left to spread a further top-up over
+        let result = env.as_contract(&contract_id, || {
+            AcademyVestingContract::top_up_grant(env.clone(), admin.clone(), grant_id, 100)
+        });
+        assert_eq!(result, Err(VestingError::InvalidSchedule));
+
+        token_admin.mint(&contract_id, &1500);
+        assert_eq!(client.claim(&grant_id, &beneficiary, &None), 1500);
+        assert_eq!(token_client.balance(&beneficiary), 1500);
+    }
+
+    #[test]
+    fn test_top_up_vesting_before_cliff() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &100, &terms(40, 0, true));
+
+        set_timestamp(&env, 20);
+        assert_eq!(client.top_up_vesting(&admin, &grant_id, &500), 1500);
+        assert_eq!(client.get_vesting(&grant_id).amount, 1500);
+
+        let zero = env.as_contract(&contract_id, || {
+            AcademyVestingContract::top_up_vesting(env.clone(), admin.clone(), grant_id, 0)
+        });
+        assert_eq!(zero, Err(VestingError::InvalidSchedule));
+
+        // The amended amount follows the original schedule
+        set_timestamp(&env, 50);
+        assert_eq!(client.get_vested_amount(&grant_id), 250);
+
+        let after_cliff = env.as_contract(&contract_id, || {
+            AcademyVestingContract::top_up_vesting(env.clone(), admin.clone(), grant_id, 100)
+        });
+        assert_eq!(after_cliff, Err(VestingError::InvalidSchedule));
+
+        set_timestamp(&env, 100);
+        token_admin.mint(&contract_id, &1500);
+        assert_eq!(client.claim(&grant_id, &beneficiary, &None), 1500);
+        assert_eq!(token_client.balance(&beneficiary), 1500);
+    }
+
+    #[test]
+    fn test_claim_external_ref_cannot_be_replayed() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        let first_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        let second_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        token_admin.mint(&contract_id, &1000);
+
+        let bridge_ref = String::from_str(&env, "bridge-tx-1");
+        assert_eq!(client.claim(&first_id, &beneficiary, &Some(bridge_ref.clone())), 500);
+        assert_eq!(client.get_claim_ref(&first_id), Some(bridge_ref.clone()));
+
+        let replay = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), second_id, beneficiary.clone(), Some(bridge_ref.clone()))
+        });
+        assert_eq!(replay, Err(VestingError::ExternalRefUsed));
+        assert!(!client.get_vesting(&second_id).claimed);
+
+        let fresh_ref = String::from_str(&env, "bridge-tx-2");
+        assert_eq!(client.claim(&second_id, &beneficiary, &Some(fresh_ref)), 500);
+        assert_eq!(token_client.balance(&beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_claim_rejects_oversized_external_ref() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        token_admin.mint(&contract_id, &500);
+
+        let too_long = String::from_bytes(&env, &[b'a'; MAX_EXTERNAL_REF_LEN as usize + 1]);
+        let result = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), Some(too_long.clone()))
+        });
+        assert_eq!(result, Err(VestingError::ExternalRefTooLong));
+        assert!(!client.get_vesting(&grant_id).claimed);
+
+        let longest = String::from_bytes(&env, &[b'a'; MAX_EXTERNAL_REF_LEN as usize]);
+        assert_eq!(client.claim(&grant_id, &beneficiary, &Some(longest.clone())), 500);
+        assert_eq!(client.get_claim_ref(&grant_id), Some(longest));
+    }
+
     #[test]
     fn test_claim_insufficient_balance() {
         let (env, admin, beneficiary, governance, contract_id) = setup_env();
@@ -138,11 +608,11 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         let client = AcademyVestingContractClient::new(&env, &contract_id);
 
         client.init(&admin, &token_id, &governance);
-        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &0, &100);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
         set_timestamp(&env, 200);
 
     let result = env.as_contract(&contract_id, || {
-        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone())
+        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
     });
     assert_eq!(result, Err(VestingError::InsufficientBalance));
     }
@@ -156,11 +626,11 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
 
         client.init(&admin, &token_id, &governance);
     let grant_id = env.as_contract(&contract_id, || {
-        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 0, 100)
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 100, terms(0, 0, true))
     }).unwrap();
         set_timestamp(&env, 200);
 
-        let result = client.try_claim(&grant_id, &other);
+        let result = client.try_claim(&grant_id, &other, &None);
         assert_eq!(result, Err(Ok(VestingError::Unauthorized)));
     }
 
@@ -171,15 +641,15 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         let client = AcademyVestingContractClient::new(&env, &contract_id);
 
         client.init(&admin, &token_id, &governance);
-        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &0, &100);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
         token_admin.mint(&contract_id, &500);
         set_timestamp(&env, 200);
 
     let _ = env.as_contract(&contract_id, || {
-        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone())
+        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
     });
     let result = env.as_contract(&contract_id, || {
-        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone())
+        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
     });
     assert_eq!(result, Err(VestingError::AlreadyClaimed));
     }
@@ -192,13 +662,13 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
 
         client.init(&admin, &token_id, &governance);
     let grant_id = env.as_contract(&contract_id, || {
-        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 1000, 500, 2000)
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 1000, 2000, terms(500, 0, true))
     }).unwrap();
         token_admin.mint(&contract_id, &500);
         set_timestamp(&env, 1200);
 
     let result = env.as_contract(&contract_id, || {
-        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone())
+        AcademyVestingContract::claim(env.clone(), grant_id, beneficiary.clone(), None)
     });
     assert_eq!(result, Err(VestingError::NotVested));
     }
@@ -208,40 +678,533 @@ use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Addres
         let (env, admin, beneficiary, governance, contract_id) = setup_env();
         let (token_id, _token_client, _token_admin) = setup_token(&env);
         let client = AcademyVestingContractClient::new(&env, &contract_id);
-        let non_admin = Address::generate(&env);
+        let non_governance = Address::generate(&env);
 
         client.init(&admin, &token_id, &governance);
     let grant_id = env.as_contract(&contract_id, || {
-        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 0, 100)
+        AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 100, terms(0, 0, true))
     }).unwrap();
 
     let invalid_timelock = env.as_contract(&contract_id, || {
-        AcademyVestingContract::revoke(env.clone(), grant_id, admin.clone(), 100)
+        AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), 100)
     });
     assert_eq!(invalid_timelock, Err(VestingError::InvalidTimelock));
 
         set_timestamp(&env, 100);
     let too_early = env.as_contract(&contract_id, || {
-        AcademyVestingContract::revoke(env.clone(), grant_id, admin.clone(), 3600)
+        AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), 3600)
     });
     assert_eq!(too_early, Err(VestingError::NotEnoughTimeForRevoke));
 
     let unauthorized = env.as_contract(&contract_id, || {
-        AcademyVestingContract::revoke(env.clone(), grant_id, non_admin.clone(), 3600)
+        AcademyVestingContract::revoke(env.clone(), grant_id, non_governance.clone(), 3600)
     });
     assert_eq!(unauthorized, Err(VestingError::Unauthorized));
 
         set_timestamp(&env, 4000);
     let _ = env.as_contract(&contract_id, || {
-        AcademyVestingContract::revoke(env.clone(), grant_id, admin.clone(), 3600)
+        AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), 3600)
     });
 
     let revoked_again = env.as_contract(&contract_id, || {
-        AcademyVestingContract::revoke(env.clone(), grant_id, admin.clone(), 3600)
+        AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), 3600)
     });
     assert_eq!(revoked_again, Err(VestingError::Revoked));
     }
 
+    #[test]
+    fn test_custom_min_revoke_delay() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        assert_eq!(client.get_min_revoke_delay(), 3600);
+
+        let week = 7 * 24 * 3600;
+        let not_governance = env.as_contract(&contract_id, || {
+            AcademyVestingContract::set_min_revoke_delay(env.clone(), admin.clone(), week)
+        });
+        assert_eq!(not_governance, Err(VestingError::Unauthorized));
+
+        client.set_min_revoke_delay(&governance, &week);
+        assert_eq!(client.get_min_revoke_delay(), week);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &(2 * week), &terms(0, 0, true));
+        set_timestamp(&env, week);
+
+        let too_short = env.as_contract(&contract_id, || {
+            AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), week - 1)
+        });
+        assert_eq!(too_short, Err(VestingError::InvalidTimelock));
+
+        client.revoke(&grant_id, &governance, &week);
+        assert!(client.get_vesting(&grant_id).revoked);
+    }
+
+    #[test]
+    fn test_revoke_all_for_beneficiary() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let other = Address::generate(&env);
+
+        client.init(&admin, &token_id, &governance);
+        let claimed_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        let first_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &10_000, &terms(0, 0, true));
+        let second_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &10_000, &terms(0, 0, true));
+        let other_id = client.grant_vesting(&admin, &other, &500, &0, &10_000, &terms(0, 0, true));
+
+        token_admin.mint(&contract_id, &500);
+        client.claim(&claimed_id, &beneficiary, &None);
+
+        set_timestamp(&env, 4000);
+        assert_eq!(client.get_beneficiary_grants(&beneficiary).len(), 3);
+        assert_eq!(client.revoke_all_for_beneficiary(&governance, &beneficiary, &3600), (2, 0));
+
+        assert!(!client.get_vesting(&claimed_id).revoked);
+        assert!(client.get_vesting(&first_id).revoked);
+        assert!(client.get_vesting(&second_id).revoked);
+        assert!(!client.get_vesting(&other_id).revoked);
+
+        // Nothing left to revoke
+        assert_eq!(client.revoke_all_for_beneficiary(&governance, &beneficiary, &3600), (0, 0));
+
+        let invalid_timelock = env.as_contract(&contract_id, || {
+            AcademyVestingContract::revoke_all_for_beneficiary(env.clone(), governance.clone(), other.clone(), 100)
+        });
+        assert_eq!(invalid_timelock, Err(VestingError::InvalidTimelock));
+    }
+
+    #[test]
+    fn test_revoke_all_counts_scheduled_and_follows_transfers() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let new_beneficiary = Address::generate(&env);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_notice_period(&admin, &1000);
+        let kept_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &10_000, &terms(0, 0, true));
+        let moved_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &10_000, &terms(0, 0, true));
+
+        // The index follows transferred grants
+        client.transfer_grant(&moved_id, &beneficiary, &new_beneficiary);
+        assert_eq!(client.get_beneficiary_grants(&beneficiary), vec![&env, kept_id]);
+        assert_eq!(client.get_beneficiary_grants(&new_beneficiary), vec![&env, moved_id]);
+
+        // Under a notice period revocations are only scheduled
+        set_timestamp(&env, 4000);
+        assert_eq!(client.revoke_all_for_beneficiary(&governance, &beneficiary, &3600), (0, 1));
+        assert_eq!(client.get_pending_revocation(&kept_id), Some(5000));
+        assert_eq!(client.get_pending_revocation(&moved_id), None);
+
+        // Already scheduled grants are skipped
+        assert_eq!(client.revoke_all_for_beneficiary(&governance, &beneficiary, &3600), (0, 0));
+    }
+
+    #[test]
+    fn test_revoke_with_notice_period() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_notice_period(&admin, &1000);
+        assert_eq!(client.get_revoke_notice_period(), 1000);
+
+        let grant_id = env.as_contract(&contract_id, || {
+            AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 500, 0, 10_000, terms(0, 0, true))
+        }).unwrap();
+
+        set_timestamp(&env, 4000);
+        client.revoke(&grant_id, &governance, &3600);
+
+        // Scheduled only: the grant keeps vesting during the notice period
+        assert_eq!(client.get_pending_revocation(&grant_id), Some(5000));
+        assert!(!client.get_vesting(&grant_id).revoked);
+
+        let again = env.as_contract(&contract_id, || {
+            AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), 3600)
+        });
+        assert_eq!(again, Err(VestingError::NoticePeriodActive));
+
+        set_timestamp(&env, 4999);
+        let early = env.as_contract(&contract_id, || {
+            AcademyVestingContract::finalize_revoke(env.clone(), grant_id, governance.clone())
+        });
+        assert_eq!(early, Err(VestingError::NoticePeriodActive));
+
+        set_timestamp(&env, 6000);
+        client.finalize_revoke(&grant_id, &governance);
+
+        let schedule = client.get_vesting(&grant_id);
+        assert!(schedule.revoked);
+        assert_eq!(schedule.revoke_time, 5000);
+        assert_eq!(client.get_pending_revocation(&grant_id), None);
+
+        let not_pending = env.as_contract(&contract_id, || {
+            AcademyVestingContract::finalize_revoke(env.clone(), grant_id, governance.clone())
+        });
+        assert_eq!(not_pending, Err(VestingError::RevocationNotPending));
+    }
+
+    #[test]
+    fn test_pending_revocation_blocks_immediate_revoke() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_notice_period(&admin, &1000);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &10_000, &terms(0, 0, true));
+
+        set_timestamp(&env, 4000);
+        client.revoke(&grant_id, &governance, &3600);
+
+        // Dropping the notice period does not allow a second, immediate revoke
+        client.set_revoke_notice_period(&admin, &0);
+        let immediate = client.try_revoke(&grant_id, &governance, &3600);
+        assert!(immediate.is_err());
+        assert!(!client.get_vesting(&grant_id).revoked);
+
+        set_timestamp(&env, 5000);
+        client.finalize_revoke(&grant_id, &governance);
+        assert_eq!(client.get_vesting(&grant_id).revoke_time, 5000);
+    }
+
+    #[test]
+    fn test_finalize_revoke_after_claim_clears_pending() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_notice_period(&admin, &1000);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        token_admin.mint(&contract_id, &500);
+
+        set_timestamp(&env, 4000);
+        client.revoke(&grant_id, &governance, &3600);
+
+        // The beneficiary claims during the notice period
+        client.claim(&grant_id, &beneficiary, &None);
+        assert_eq!(token_client.balance(&beneficiary), 500);
+
+        set_timestamp(&env, 5000);
+        client.finalize_revoke(&grant_id, &governance);
+        assert_eq!(client.get_pending_revocation(&grant_id), None);
+        assert!(!client.get_vesting(&grant_id).revoked);
+    }
+
+    #[test]
+    fn test_upgrade_respects_timelock() {
+        let (env, admin, _beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        let nothing_pending = env.as_contract(&contract_id, || {
+            AcademyVestingContract::execute_upgrade(env.clone(), governance.clone())
+        });
+        assert_eq!(nothing_pending, Err(VestingError::NoUpgradePending));
+
+        // Test environments accept an empty module as uploaded contract code
+        let wasm_hash = env.deployer().upload_contract_wasm(soroban_sdk::Bytes::new(&env));
+
+        let not_governance = env.as_contract(&contract_id, || {
+            AcademyVestingContract::propose_upgrade(env.clone(), admin.clone(), wasm_hash.clone(), 1000 + MIN_UPGRADE_DELAY)
+        });
+        assert_eq!(not_governance, Err(VestingError::Unauthorized));
+
+        // The timelock cannot be shorter than MIN_UPGRADE_DELAY
+        let too_soon = env.as_contract(&contract_id, || {
+            AcademyVestingContract::propose_upgrade(env.clone(), governance.clone(), wasm_hash.clone(), 1000 + MIN_UPGRADE_DELAY - 1)
+        });
+        assert_eq!(too_soon, Err(VestingError::InvalidTimelock));
+        let in_past = env.as_contract(&contract_id, || {
+            AcademyVestingContract::propose_upgrade(env.clone(), governance.clone(), wasm_hash.clone(), 500)
+        });
+        assert_eq!(in_past, Err(VestingError::InvalidTimelock));
+
+        let earliest = 1000 + MIN_UPGRADE_DELAY;
+        client.propose_upgrade(&governance, &wasm_hash, &earliest);
+        assert_eq!(client.get_pending_upgrade(), Some((wasm_hash.clone(), earliest)));
+
+        set_timestamp(&env, earliest - 1);
+        let premature = env.as_contract(&contract_id, || {
+            AcademyVestingContract::execute_upgrade(env.clone(), governance.clone())
+        });
+        assert_eq!(premature, Err(VestingError::UpgradeTimelockActive));
+
+        set_timestamp(&env, earliest);
+        let executed = env.as_contract(&contract_id, || {
+            AcademyVestingContract::execute_upgrade(env.clone(), governance.clone())
+        });
+        assert_eq!(executed, Ok(()));
+
+        let topic: Symbol = env.events().all().last().unwrap().1.first().unwrap().try_into_val(&env).unwrap();
+        assert_eq!(topic, symbol_short!("upg_exec"));
+    }
+
+    #[test]
+    fn test_admin_cannot_revoke_and_governance_cannot_grant() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        let gov_grant = env.as_contract(&contract_id, || {
+            AcademyVestingContract::grant_vesting(env.clone(), governance.clone(), beneficiary.clone(), 500, 0, 100, terms(0, 0, true))
+        });
+        assert_eq!(gov_grant, Err(VestingError::Unauthorized));
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        set_timestamp(&env, 4000);
+
+        let admin_revoke = env.as_contract(&contract_id, || {
+            AcademyVestingContract::revoke(env.clone(), grant_id, admin.clone(), 3600)
+        });
+        assert_eq!(admin_revoke, Err(VestingError::Unauthorized));
+
+        client.revoke(&grant_id, &governance, &3600);
+        assert!(client.get_vesting(&grant_id).revoked);
+    }
+
+    #[test]
+    fn test_transfer_governance() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let new_governance = Address::generate(&env);
+
+        client.init(&admin, &token_id, &governance);
+
+        let by_admin = env.as_contract(&contract_id, || {
+            AcademyVestingContract::transfer_governance(env.clone(), admin.clone(), new_governance.clone())
+        });
+        assert_eq!(by_admin, Err(VestingError::Unauthorized));
+
+        client.transfer_governance(&governance, &new_governance);
+        let (_, _, stored_gov) = client.get_info();
+        assert_eq!(stored_gov, new_governance);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        set_timestamp(&env, 4000);
+
+        let old_governance = env.as_contract(&contract_id, || {
+            AcademyVestingContract::revoke(env.clone(), grant_id, governance.clone(), 3600)
+        });
+        assert_eq!(old_governance, Err(VestingError::Unauthorized));
+
+        client.revoke(&grant_id, &new_governance, &3600);
+        assert!(client.get_vesting(&grant_id).revoked);
+    }
+
+    #[test]
+    fn test_transfer_grant_respects_transfer_lock() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let new_beneficiary = Address::generate(&env);
+
+        client.init(&admin, &token_id, &governance);
+        token_admin.mint(&contract_id, &1000);
+
+        let transferable_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, true));
+        let locked_id = client.grant_vesting(&admin, &beneficiary, &500, &0, &100, &terms(0, 0, false));
+
+        client.transfer_grant(&transferable_id, &beneficiary, &new_beneficiary);
+        assert_eq!(client.get_vesting(&transferable_id).beneficiary, new_beneficiary);
+
+        let locked = env.as_contract(&contract_id, || {
+            AcademyVestingContract::transfer_grant(env.clone(), locked_id, beneficiary.clone(), new_beneficiary.clone())
+        });
+        assert_eq!(locked, Err(VestingError::NotTransferable));
+        assert_eq!(client.get_vesting(&locked_id).beneficiary, beneficiary);
+
+        // Only the new beneficiary can claim the transferred grant
+        set_timestamp(&env, 100);
+        let old_owner_claim = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), transferable_id, beneficiary.clone(), None)
+        });
+        assert_eq!(old_owner_claim, Err(VestingError::Unauthorized));
+
+        client.claim(&transferable_id, &new_beneficiary, &None);
+        assert_eq!(token_client.balance(&new_beneficiary), 500);
+    }
+
+    #[test]
+    fn test_claim_fee_reduced_by_academy_badge_discount() {
+        use academy_rewards::{AcademyRewardsContract, AcademyRewardsContractClient, BadgeTypeConfig};
+
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let fee_recipient = Address::generate(&env);
+        let other_beneficiary = Address::generate(&env);
+
+        // Academy rewards: beneficiary holds a 50% badge
+        let academy_id = env.register_contract(None, AcademyRewardsContract);
+        let academy = AcademyRewardsContractClient::new(&env, &academy_id);
+        academy.initialize(&admin);
+        academy.create_badge_type(&admin, &1, &BadgeTypeConfig {
+            name: soroban_sdk::String::from_str(&env, "Gold"),
+            discount_bps: 5000,
+            max_redemptions: 0,
+            validity_duration: 0,
+            uri: soroban_sdk::String::from_str(&env, ""),
+        });
+        academy.mint_badge(&admin, &beneficiary, &1);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_claim_fee(&admin, &200, &fee_recipient);
+        client.set_academy_contract(&admin, &academy_id);
+        token_admin.mint(&contract_id, &2000);
+
+        let badge_grant = client.grant_vesting(&admin, &beneficiary, &1000, &0, &100, &terms(0, 0, true));
+        let plain_grant = client.grant_vesting(&admin, &other_beneficiary, &1000, &0, &100, &terms(0, 0, true));
+        set_timestamp(&env, 100);
+
+        // 2% fee on 1000 is 20; the badge halves it to 10
+        assert_eq!(client.claim(&badge_grant, &beneficiary, &None), 990);
+        assert_eq!(token_client.balance(&beneficiary), 990);
+        assert_eq!(token_client.balance(&fee_recipient), 10);
+
+        // Without a badge the full fee applies
+        assert_eq!(client.claim(&plain_grant, &other_beneficiary, &None), 980);
+        assert_eq!(token_client.balance(&fee_recipient), 30);
+    }
+
+    #[test]
+    fn test_claim_fee_ignores_failing_academy_lookup() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+        let fee_recipient = Address::generate(&env);
+
+        // A contract without get_user_discount stands in for a broken academy
+        let broken_academy = env.register_contract(None, MockIdentityRegistry);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_claim_fee(&admin, &200, &fee_recipient);
+        client.set_academy_contract(&admin, &broken_academy);
+        token_admin.mint(&contract_id, &1000);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &100, &terms(0, 0, true));
+        set_timestamp(&env, 100);
+
+        // The claim still goes through with the full, undiscounted fee
+        assert_eq!(client.claim(&grant_id, &beneficiary, &None), 980);
+        assert_eq!(token_client.balance(&fee_recipient), 20);
+    }
+
+    #[test]
+    fn test_revoke_claim_window_then_sweep() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_claim_window(&admin, &1000);
+        token_admin.mint(&contract_id, &2000);
+
+        let claimed_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &10000, &terms(0, 0, true));
+        let swept_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &10000, &terms(0, 0, true));
+
+        set_timestamp(&env, 5000);
+        client.revoke(&claimed_id, &governance, &3600);
+        client.revoke(&swept_id, &governance, &3600);
+
+        // Within the window the beneficiary gets what had vested at revocation
+        set_timestamp(&env, 5500);
+        assert_eq!(client.claim(&claimed_id, &beneficiary, &None), 500);
+        assert_eq!(token_client.balance(&beneficiary), 500);
+
+        let too_early = env.as_contract(&contract_id, || {
+            AcademyVestingContract::sweep_revoked(env.clone(), admin.clone(), swept_id)
+        });
+        assert_eq!(too_early, Err(VestingError::ClaimWindowOpen));
+
+        // After the window the admin sweeps the grant back
+        set_timestamp(&env, 6001);
+        assert_eq!(client.sweep_revoked(&admin, &swept_id), 1000);
+        assert_eq!(token_client.balance(&admin), 1000);
+
+        let late_claim = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), swept_id, beneficiary.clone(), None)
+        });
+        assert!(late_claim.is_err());
+        assert_eq!(token_client.balance(&beneficiary), 500);
+    }
+
+    #[test]
+    fn test_sweep_recovers_remainder_after_window_claim() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_claim_window(&admin, &1000);
+        token_admin.mint(&contract_id, &1000);
+
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &10000, &terms(0, 0, true));
+        set_timestamp(&env, 5000);
+        client.revoke(&grant_id, &governance, &3600);
+
+        // The beneficiary takes the vested half inside the window
+        set_timestamp(&env, 5500);
+        assert_eq!(client.claim(&grant_id, &beneficiary, &None), 500);
+        assert_eq!(client.get_vesting(&grant_id).claimed_amount, 500);
+
+        // The unvested half can be swept without waiting for the window to close
+        assert_eq!(client.sweep_revoked(&admin, &grant_id), 500);
+        assert_eq!(token_client.balance(&admin), 500);
+        assert_eq!(token_client.balance(&contract_id), 0);
+
+        let again = env.as_contract(&contract_id, || {
+            AcademyVestingContract::sweep_revoked(env.clone(), admin.clone(), grant_id)
+        });
+        assert_eq!(again, Err(VestingError::AlreadyClaimed));
+    }
+
+    #[test]
+    fn test_revoke_claim_window_is_fixed_at_revocation() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+        client.set_revoke_claim_window(&admin, &1000);
+        token_admin.mint(&contract_id, &2000);
+
+        let open_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &10000, &terms(0, 0, true));
+        let closed_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &10000, &terms(0, 0, true));
+        set_timestamp(&env, 5000);
+        client.revoke(&open_id, &governance, &3600);
+
+        // Shortening the window does not cut short a grant already revoked
+        client.set_revoke_claim_window(&admin, &0);
+        set_timestamp(&env, 5500);
+        let too_early = env.as_contract(&contract_id, || {
+            AcademyVestingContract::sweep_revoked(env.clone(), admin.clone(), open_id)
+        });
+        assert_eq!(too_early, Err(VestingError::ClaimWindowOpen));
+        assert_eq!(client.claim(&open_id, &beneficiary, &None), 500);
+
+        // Widening it does not reopen one revoked without a window
+        client.revoke(&closed_id, &governance, &3600);
+        client.set_revoke_claim_window(&admin, &10_000);
+        let reopened = env.as_contract(&contract_id, || {
+            AcademyVestingContract::claim(env.clone(), closed_id, beneficiary.clone(), None)
+        });
+        assert_eq!(reopened, Err(VestingError::Revoked));
+    }
+
     #[test]
     fn test_get_vesting_and_vested_amount_errors() {
         let (env, admin, _beneficiary, governance, contract_id) = setup_env();
@@ -266,3 +1229,54 @@ fn set_timestamp(env: &Env, timestamp: u64) {
     ledger_info.timestamp = timestamp;
     env.ledger().set(ledger_info);
 }
+
+    #[contract]
+    struct MockIdentityRegistry;
+
+    #[contractimpl]
+    impl MockIdentityRegistry {
+        pub fn register(env: Env, account: Address) {
+            env.storage().persistent().set(&account, &true);
+        }
+
+        pub fn is_registered(env: Env, account: Address) -> bool {
+            env.storage().persistent().get(&account).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn test_identity_registry_gates_grants_and_transfers() {
+        let (env, admin, beneficiary, governance, contract_id) = setup_env();
+        let (token_id, _token_client, _token_admin) = setup_token(&env);
+        let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_id, &governance);
+
+        let registry_id = env.register_contract(None, MockIdentityRegistry);
+        let registry = MockIdentityRegistryClient::new(&env, &registry_id);
+        client.set_identity_registry(&admin, &Some(registry_id.clone()));
+        assert_eq!(client.get_identity_registry(), Some(registry_id));
+
+        let rejected = env.as_contract(&contract_id, || {
+            AcademyVestingContract::grant_vesting(env.clone(), admin.clone(), beneficiary.clone(), 1000, 0, 100, terms(10, 0, true))
+        });
+        assert_eq!(rejected, Err(VestingError::BeneficiaryNotRegistered));
+
+        registry.register(&beneficiary);
+        let grant_id = client.grant_vesting(&admin, &beneficiary, &1000, &0, &100, &terms(10, 0, true));
+
+        let new_beneficiary = Address::generate(&env);
+        let blocked = env.as_contract(&contract_id, || {
+            AcademyVestingContract::transfer_grant(env.clone(), grant_id, beneficiary.clone(), new_beneficiary.clone())
+        });
+        assert_eq!(blocked, Err(VestingError::BeneficiaryNotRegistered));
+
+        registry.register(&new_beneficiary);
+        client.transfer_grant(&grant_id, &beneficiary, &new_beneficiary);
+        assert_eq!(client.get_vesting(&grant_id).beneficiary, new_beneficiary);
+
+        // Clearing the registry lifts the check
+        client.set_identity_registry(&admin, &None);
+        let anyone = Address::generate(&env);
+        client.grant_vesting(&admin, &anyone, &1000, &0, &100, &terms(10, 0, true));
+    }
@@ -0,0 +1,83 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, contracterror, Address, Env, Symbol};
+
+// Contract Errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+}
+
+// Storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    GlobalHalt,
+}
+
+/// Single switch that Stellara contracts consult before state-changing operations,
+/// so an incident responder can halt every contract at once.
+#[contract]
+pub struct EmergencyRegistryContract;
+
+#[contractimpl]
+impl EmergencyRegistryContract {
+    /// Initialize the registry with the admin allowed to toggle the halt
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::GlobalHalt, &false);
+
+        Ok(())
+    }
+
+    /// Halt or resume state-changing operations in every contract that consults the registry
+    pub fn set_global_halt(env: Env, admin: Address, halted: bool) -> Result<(), ContractError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        if admin != stored_admin {
+            return Err(ContractError::Unauthorized);
+        }
+
+        env.storage().instance().set(&DataKey::GlobalHalt, &halted);
+        env.events().publish(
+            (Symbol::new(&env, "global_halt"), admin),
+            (halted, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Whether the global halt is active (false before initialization)
+    pub fn is_halted(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::GlobalHalt)
+            .unwrap_or(false)
+    }
+
+    /// Get the registry admin
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
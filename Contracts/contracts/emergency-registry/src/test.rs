@@ -0,0 +1,44 @@
+use crate::{ContractError, EmergencyRegistryContract, EmergencyRegistryContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup_env() -> (Env, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, EmergencyRegistryContract);
+    let admin = Address::generate(&env);
+
+    (env, admin, contract_id)
+}
+
+#[test]
+fn test_initialize_and_double_init() {
+    let (env, admin, contract_id) = setup_env();
+    let client = EmergencyRegistryContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_admin(), admin);
+    assert!(!client.is_halted());
+
+    let result = client.try_initialize(&admin);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_only_admin_toggles_global_halt() {
+    let (env, admin, contract_id) = setup_env();
+    let client = EmergencyRegistryContractClient::new(&env, &contract_id);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let result = client.try_set_global_halt(&stranger, &true);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert!(!client.is_halted());
+
+    client.set_global_halt(&admin, &true);
+    assert!(client.is_halted());
+
+    client.set_global_halt(&admin, &false);
+    assert!(!client.is_halted());
+}
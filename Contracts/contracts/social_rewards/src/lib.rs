@@ -95,6 +95,11 @@ impl SocialRewardsContract {
         let stats_key = symbol_short!("stats");
         env.storage().persistent().set(&stats_key, &stats);
 
+        env.events().publish(
+            (Symbol::new(&env, "initialized"),),
+            (admin, reward_token),
+        );
+
         Ok(())
     }
 
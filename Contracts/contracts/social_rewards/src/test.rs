@@ -48,6 +48,22 @@ fn test_init_and_get_info() {
     assert_eq!(stored_token, token_id);
 }
 
+#[test]
+fn test_init_emits_initialized_event() {
+    let (env, admin, _user, contract_id) = setup_env();
+    let (token_id, _token_client, _token_admin) = setup_token(&env);
+    let client = SocialRewardsContractClient::new(&env, &contract_id);
+
+    client.init(&admin, &token_id);
+
+    let (event_contract, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(event_contract, contract_id);
+    let topic: Symbol = topics.first().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "initialized"));
+    let data: (Address, Address) = data.try_into_val(&env).unwrap();
+    assert_eq!(data, (admin, token_id));
+}
+
 #[test]
 fn test_init_twice_fails() {
     let (env, admin, _user, contract_id) = setup_env();
@@ -1,7 +1,16 @@
-use soroban_sdk::Env;
-use crate::storage::get_admin;
+use soroban_sdk::{Address, Env};
+use crate::storage::{get_admin, is_minter};
 
+/// Admin entrypoints take no caller argument, so requiring the stored admin's
+/// signature is the caller check: no other address can authorize the call.
 pub fn require_admin(env: &Env) {
     let admin = get_admin(env);
     admin.require_auth();
 }
+
+pub fn require_minter(env: &Env, minter: &Address) {
+    minter.require_auth();
+    if *minter != get_admin(env) && !is_minter(env, minter) {
+        panic!("Not a minter");
+    }
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    Paused = 1,
+    GloballyHalted = 2,
+    CallbackFailed = 3,
+    InvalidDecimals = 4,
+    InvalidMetadata = 5,
+    TransferLimitExceeded = 6,
+    AllowanceExpired = 7,
+}
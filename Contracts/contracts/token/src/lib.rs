@@ -1,13 +1,19 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, Address, Env, Error, IntoVal, String, Symbol, Val, Vec,
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, Error, IntoVal, String, Symbol,
+    Val, Vec,
 };
 
 mod admin;
+mod error;
 mod storage;
 
-use storage::{Allowance, TokenMetadata};
+pub use error::TokenError;
+use storage::{Allowance, ClawbackRecord, MintMemoRecord, TokenMetadata};
+
+/// Largest `decimals` accepted by `initialize`.
+pub const MAX_DECIMALS: u32 = 18;
 
 #[contract]
 pub struct TokenContract;
@@ -15,14 +21,104 @@ pub struct TokenContract;
 #[contractimpl]
 impl TokenContract {
     /// Initialize token metadata and admin.
-    pub fn initialize(env: Env, admin: Address, name: String, symbol: String, decimals: u32) {
+    ///
+    /// With `require_authorization`, accounts are unauthorized until the admin allows them;
+    /// the admin itself is authorized so it can distribute the supply.
+    /// `decimals` may be at most `MAX_DECIMALS`, and `name` and `symbol` must be non-empty.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        require_authorization: bool,
+    ) {
         if storage::has_admin(&env) {
             panic!("Already initialized");
         }
+        if decimals > MAX_DECIMALS {
+            panic_with_error!(env, TokenError::InvalidDecimals);
+        }
+        if name.len() == 0 || symbol.len() == 0 {
+            panic_with_error!(env, TokenError::InvalidMetadata);
+        }
         admin.require_auth();
         storage::set_admin(&env, &admin);
-        storage::set_metadata(&env, &TokenMetadata { name, symbol, decimals });
+        storage::set_metadata(
+            &env,
+            &TokenMetadata {
+                name: name.clone(),
+                symbol: symbol.clone(),
+                decimals,
+            },
+        );
         storage::set_total_supply(&env, 0);
+        storage::set_require_authorization(&env, require_authorization);
+        if require_authorization {
+            storage::set_authorized(&env, &admin, true);
+        }
+        env.events().publish(
+            (Symbol::new(&env, "initialized"),),
+            (admin, name, symbol, decimals),
+        );
+    }
+
+    /// Initialize the token and mint `initial_supply` to the admin in one call.
+    pub fn initialize_with_supply(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        initial_supply: i128,
+        require_authorization: bool,
+    ) {
+        ensure_nonnegative(initial_supply);
+        Self::initialize(
+            env.clone(),
+            admin.clone(),
+            name,
+            symbol,
+            decimals,
+            require_authorization,
+        );
+
+        mint_balance(&env, &admin, initial_supply);
+        env.events().publish(
+            (Symbol::new(&env, "mint"), admin.clone(), admin),
+            initial_supply,
+        );
+    }
+
+    /// Initialize the token with a per-transfer cap; `transfer` and `transfer_from`
+    /// reject amounts above `max_transfer` with `TokenError::TransferLimitExceeded`.
+    pub fn initialize_with_max_transfer(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        max_transfer: i128,
+        require_authorization: bool,
+    ) {
+        if max_transfer <= 0 {
+            panic!("Invalid max transfer");
+        }
+        Self::initialize(
+            env.clone(),
+            admin,
+            name,
+            symbol,
+            decimals,
+            require_authorization,
+        );
+
+        storage::set_max_transfer(&env, max_transfer);
+    }
+
+    /// Per-transfer cap, if the token was initialized with one.
+    pub fn max_transfer(env: Env) -> Option<i128> {
+        storage::get_max_transfer(&env)
     }
 
     // --------- Standard token interface ---------
@@ -30,6 +126,11 @@ impl TokenContract {
         storage::get_allowance_amount(&env, &from, &spender)
     }
 
+    /// The stored allowance amount and expiration ledger, even if expired. Read-only.
+    pub fn get_allowance_details(env: Env, from: Address, spender: Address) -> Allowance {
+        storage::get_allowance(&env, &from, &spender)
+    }
+
     pub fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
         from.require_auth();
         ensure_nonnegative(amount);
@@ -38,6 +139,10 @@ impl TokenContract {
         if expiration_ledger < current_ledger && amount != 0 {
             panic!("Invalid expiration");
         }
+        let min_ledgers = storage::min_allowance_ledgers(&env);
+        if amount != 0 && expiration_ledger < current_ledger.saturating_add(min_ledgers) {
+            panic!("Expiration too soon");
+        }
 
         let allowance = Allowance {
             amount,
@@ -51,29 +156,67 @@ impl TokenContract {
         );
     }
 
+    /// Delete an expired allowance entry to reclaim its storage. Anyone may call this.
+    /// Returns `false` if there was no expired entry to remove.
+    pub fn prune_allowance(env: Env, from: Address, spender: Address) -> bool {
+        if !storage::remove_expired_allowance(&env, &from, &spender) {
+            return false;
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "allowance_expired"), from, spender),
+            env.ledger().sequence(),
+        );
+        true
+    }
+
     pub fn balance(env: Env, id: Address) -> i128 {
         storage::balance_of(&env, &id)
     }
 
     pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
         from.require_auth();
+        require_not_paused(&env);
         ensure_nonnegative(amount);
+        require_within_max_transfer(&env, amount);
         require_authorized(&env, &from);
+        require_authorized_recipient(&env, &to);
 
-        internal_transfer(&env, &from, &to, amount);
+        transfer_with_burn(&env, &from, &to, amount);
+    }
+
+    /// Transfer to `to`, then invoke `func(args)` on it; the transfer reverts if the call fails.
+    pub fn transfer_and_call(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        func: Symbol,
+        args: Vec<Val>,
+    ) -> Val {
+        Self::transfer(env.clone(), from, to.clone(), amount);
+
+        match env.try_invoke_contract::<Val, Error>(&to, &func, args) {
+            Ok(Ok(result)) => result,
+            _ => panic_with_error!(env, TokenError::CallbackFailed),
+        }
     }
 
     pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
         spender.require_auth();
+        require_not_paused(&env);
         ensure_nonnegative(amount);
+        require_within_max_transfer(&env, amount);
         require_authorized(&env, &from);
+        require_authorized_recipient(&env, &to);
 
         spend_allowance(&env, &from, &spender, amount);
-        internal_transfer(&env, &from, &to, amount);
+        transfer_with_burn(&env, &from, &to, amount);
     }
 
     pub fn burn(env: Env, from: Address, amount: i128) {
         from.require_auth();
+        require_not_paused(&env);
         ensure_nonnegative(amount);
         require_authorized(&env, &from);
 
@@ -84,6 +227,7 @@ impl TokenContract {
 
     pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
         spender.require_auth();
+        require_not_paused(&env);
         ensure_nonnegative(amount);
         require_authorized(&env, &from);
 
@@ -129,40 +273,264 @@ impl TokenContract {
         );
     }
 
+    /// Set authorization for every account in `accounts` (admin only), e.g. to allow a KYC'd cohort.
+    pub fn set_authorized_batch(env: Env, accounts: Vec<Address>, authorize: bool) {
+        admin::require_admin(&env);
+        if accounts.is_empty() {
+            panic!("Empty batch");
+        }
+
+        for id in accounts.iter() {
+            storage::set_authorized(&env, &id, authorize);
+            env.events().publish(
+                (Symbol::new(&env, "set_authorized"), id),
+                authorize,
+            );
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "set_authorized_batch"),),
+            (accounts.len(), authorize),
+        );
+    }
+
+    /// Halt transfers, burns and mints; clawback stays available for incident recovery.
+    pub fn set_paused(env: Env, paused: bool) {
+        admin::require_admin(&env);
+        storage::set_paused(&env, paused);
+        env.events()
+            .publish((Symbol::new(&env, "set_paused"),), paused);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        storage::is_paused(&env)
+    }
+
+    /// Require non-zero approvals to stay live for at least `ledgers` ledgers (0 disables the check).
+    pub fn set_min_allowance_ledgers(env: Env, ledgers: u32) {
+        admin::require_admin(&env);
+        storage::set_min_allowance_ledgers(&env, ledgers);
+        env.events()
+            .publish((Symbol::new(&env, "set_min_allowance_ledgers"),), ledgers);
+    }
+
+    pub fn min_allowance_ledgers(env: Env) -> u32 {
+        storage::min_allowance_ledgers(&env)
+    }
+
+    /// Burn `bps` basis points of every `transfer`/`transfer_from`; the recipient gets the rest.
+    pub fn set_burn_on_transfer_bps(env: Env, bps: u32) {
+        admin::require_admin(&env);
+        if bps > 10_000 {
+            panic!("Invalid burn rate");
+        }
+        storage::set_burn_on_transfer_bps(&env, bps);
+        env.events()
+            .publish((Symbol::new(&env, "set_burn_on_transfer_bps"),), bps);
+    }
+
+    pub fn burn_on_transfer_bps(env: Env) -> u32 {
+        storage::burn_on_transfer_bps(&env)
+    }
+
+    /// Also halt whenever the shared emergency registry reports a global halt.
+    pub fn set_emergency_registry(env: Env, registry: Address) {
+        admin::require_admin(&env);
+        storage::set_emergency_registry(&env, &registry);
+        storage::clear_cached_halt(&env);
+        env.events()
+            .publish((Symbol::new(&env, "set_emergency_registry"),), registry);
+    }
+
+    pub fn emergency_registry(env: Env) -> Option<Address> {
+        storage::get_emergency_registry(&env)
+    }
+
+    /// Replace the token's code with an uploaded wasm; storage and balances are kept.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        admin::require_admin(&env);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        env.events()
+            .publish((Symbol::new(&env, "upgraded"),), new_wasm_hash);
+    }
+
     pub fn authorized(env: Env, id: Address) -> bool {
         storage::get_authorized(&env, &id)
     }
 
-    pub fn mint(env: Env, to: Address, amount: i128) {
+    pub fn add_minter(env: Env, minter: Address) {
+        admin::require_admin(&env);
+        storage::set_minter(&env, &minter, true);
+        env.events()
+            .publish((Symbol::new(&env, "minter_added"),), minter);
+    }
+
+    pub fn remove_minter(env: Env, minter: Address) {
         admin::require_admin(&env);
+        storage::set_minter(&env, &minter, false);
+        env.events()
+            .publish((Symbol::new(&env, "minter_removed"),), minter);
+    }
+
+    pub fn is_minter(env: Env, id: Address) -> bool {
+        storage::is_minter(&env, &id)
+    }
+
+    /// Mint `amount` to `to`; `minter` must be the admin or a registered minter.
+    pub fn mint(env: Env, minter: Address, to: Address, amount: i128) {
+        admin::require_minter(&env, &minter);
+        require_not_paused(&env);
+        ensure_nonnegative(amount);
+
+        mint_balance(&env, &to, amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "mint"), minter, to),
+            amount,
+        );
+    }
+
+    /// Mint that also stores a memo (e.g. an airdrop campaign id) for later reconciliation.
+    pub fn mint_with_memo(env: Env, minter: Address, to: Address, amount: i128, memo: String) {
+        admin::require_minter(&env, &minter);
+        require_not_paused(&env);
         ensure_nonnegative(amount);
 
-        let balance = storage::balance_of(&env, &to);
-        let new_balance = balance.checked_add(amount).expect("Overflow");
-        storage::set_balance(&env, &to, &new_balance);
+        mint_balance(&env, &to, amount);
+
+        let record = MintMemoRecord {
+            to: to.clone(),
+            amount,
+            memo: memo.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        storage::push_mint_memo(&env, &to, &record);
+
+        env.events().publish(
+            (Symbol::new(&env, "mint"), minter, to),
+            (amount, memo),
+        );
+    }
+
+    pub fn get_mint_memo(env: Env, account: Address, index: u32) -> Option<MintMemoRecord> {
+        storage::get_mint_memo(&env, &account, index)
+    }
+
+    /// Burn `amounts[i]` from `accounts[i]` for every pair (admin only); reverts entirely if any burn fails.
+    pub fn burn_batch(env: Env, accounts: Vec<Address>, amounts: Vec<i128>) {
+        admin::require_admin(&env);
+        require_not_paused(&env);
+        if accounts.len() != amounts.len() {
+            panic!("Length mismatch");
+        }
+
+        let mut total_burned: i128 = 0;
+        for (account, amount) in accounts.iter().zip(amounts.iter()) {
+            ensure_nonnegative(amount);
+
+            let balance = storage::balance_of(&env, &account);
+            if amount > balance {
+                panic!("Insufficient balance");
+            }
+            storage::set_balance(&env, &account, &(balance - amount));
+            total_burned = total_burned.checked_add(amount).expect("Overflow");
+
+            env.events()
+                .publish((Symbol::new(&env, "burn"), account), amount);
+        }
 
         let supply = storage::total_supply(&env);
-        let new_supply = supply.checked_add(amount).expect("Overflow");
+        let new_supply = supply.checked_sub(total_burned).expect("Overflow");
         storage::set_total_supply(&env, new_supply);
+        storage::increment_metrics(&env, 0, 0, accounts.len() as u64);
+    }
+
+    /// Burn `amount` from `from`; `minter` must be the admin or a registered minter.
+    pub fn clawback(env: Env, minter: Address, from: Address, amount: i128) {
+        admin::require_minter(&env, &minter);
+        ensure_nonnegative(amount);
 
+        burn_balance(&env, &from, amount);
         env.events().publish(
-            (Symbol::new(&env, "mint"), storage::get_admin(&env), to),
+            (Symbol::new(&env, "clawback"), minter, from),
             amount,
         );
     }
 
-    pub fn clawback(env: Env, from: Address, amount: i128) {
-        admin::require_admin(&env);
+    /// Clawback that also stores an audit record of why it happened.
+    pub fn clawback_with_reason(env: Env, minter: Address, from: Address, amount: i128, reason: String) {
+        admin::require_minter(&env, &minter);
         ensure_nonnegative(amount);
 
         burn_balance(&env, &from, amount);
-        env.events().publish(
-            (Symbol::new(&env, "clawback"), storage::get_admin(&env), from),
+
+        let record = ClawbackRecord {
+            from: from.clone(),
             amount,
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        storage::push_clawback_record(&env, &from, &record);
+
+        env.events().publish(
+            (Symbol::new(&env, "clawback"), minter, from),
+            (amount, reason),
         );
     }
 
+    pub fn get_clawback_record(env: Env, account: Address, index: u32) -> Option<ClawbackRecord> {
+        storage::get_clawback_record(&env, &account, index)
+    }
+
+    // --------- Governance snapshots ---------
+    /// Record the current total supply under a new snapshot id and return it.
+    pub fn snapshot(env: Env) -> u64 {
+        admin::require_admin(&env);
+
+        let id = storage::snapshot_id(&env) + 1;
+        let supply = storage::total_supply(&env);
+        storage::set_snapshot_id(&env, id);
+        storage::set_snapshot_supply(&env, id, supply);
+
+        env.events()
+            .publish((Symbol::new(&env, "snapshot"), id), supply);
+        id
+    }
+
+    /// Balance of `account` as of snapshot `id`.
+    ///
+    /// Reads a fixed number of entries regardless of how many snapshots have been taken.
+    pub fn balance_at_snapshot(env: Env, id: u64, account: Address) -> i128 {
+        let current = storage::snapshot_id(&env);
+        if id == 0 || id > current {
+            panic!("Invalid snapshot");
+        }
+
+        // The first checkpoint written at or after `id` holds the balance at `id`;
+        // if the balance never changed since, the live balance is still accurate.
+        let checkpoints = storage::snapshot_checkpoints(&env, &account);
+        let index = match checkpoints.binary_search(id) {
+            Ok(index) | Err(index) => index,
+        };
+        match checkpoints.get(index) {
+            Some(snapshot) => storage::get_snapshot_balance(&env, snapshot, &account)
+                .expect("Snapshot checkpoint missing"),
+            None => storage::balance_of(&env, &account),
+        }
+    }
+
+    /// Total supply as of snapshot `id`.
+    pub fn total_supply_at_snapshot(env: Env, id: u64) -> i128 {
+        storage::get_snapshot_supply(&env, id)
+    }
+
     // --------- Additional helpers ---------
+    /// Operation counters: (transfers, mints, burns). Clawbacks count as burns.
+    pub fn get_metrics(env: Env) -> (u64, u64, u64) {
+        storage::get_metrics(&env)
+    }
+
     pub fn total_supply(env: Env) -> i128 {
         storage::total_supply(&env)
     }
@@ -174,27 +542,65 @@ fn ensure_nonnegative(amount: i128) {
     }
 }
 
+fn require_within_max_transfer(env: &Env, amount: i128) {
+    if let Some(max_transfer) = storage::get_max_transfer(env) {
+        if amount > max_transfer {
+            panic_with_error!(env, TokenError::TransferLimitExceeded);
+        }
+    }
+}
+
+fn require_not_paused(env: &Env) {
+    if storage::is_paused(env) {
+        panic_with_error!(env, TokenError::Paused);
+    }
+    if is_globally_halted(env) {
+        panic_with_error!(env, TokenError::GloballyHalted);
+    }
+}
+
+/// Query the emergency registry at most once per ledger.
+fn is_globally_halted(env: &Env) -> bool {
+    let registry = match storage::get_emergency_registry(env) {
+        Some(registry) => registry,
+        None => return false,
+    };
+    if let Some(halted) = storage::get_cached_halt(env) {
+        return halted;
+    }
+
+    let halted: bool =
+        env.invoke_contract(&registry, &Symbol::new(env, "is_halted"), Vec::new(env));
+    storage::set_cached_halt(env, halted);
+    halted
+}
+
 fn require_authorized(env: &Env, id: &Address) {
     if !storage::get_authorized(env, id) {
         panic!("Unauthorized");
     }
 }
 
+/// Permissioned tokens only deliver to accounts the admin has allowed.
+fn require_authorized_recipient(env: &Env, to: &Address) {
+    if storage::require_authorization(env) {
+        require_authorized(env, to);
+    }
+}
+
 fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
     let allowance = storage::get_allowance(env, from, spender);
-    let current_ledger = env.ledger().sequence();
 
-    let available = if allowance.expiration_ledger < current_ledger {
-        0
-    } else {
-        allowance.amount
-    };
+    // An allowance stays usable through its expiration ledger.
+    if allowance.amount > 0 && allowance.expiration_ledger < env.ledger().sequence() {
+        panic_with_error!(env, TokenError::AllowanceExpired);
+    }
 
-    if amount > available {
+    if amount > allowance.amount {
         panic!("Allowance exceeded");
     }
 
-    let remaining = available.checked_sub(amount).expect("Overflow");
+    let remaining = allowance.amount.checked_sub(amount).expect("Overflow");
     let updated = Allowance {
         amount: remaining,
         expiration_ledger: allowance.expiration_ledger,
@@ -202,6 +608,18 @@ fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
     storage::set_allowance(env, from, spender, &updated);
 }
 
+fn mint_balance(env: &Env, to: &Address, amount: i128) {
+    let balance = storage::balance_of(env, to);
+    let new_balance = balance.checked_add(amount).expect("Overflow");
+    storage::set_balance(env, to, &new_balance);
+
+    let supply = storage::total_supply(env);
+    let new_supply = supply.checked_add(amount).expect("Overflow");
+    storage::set_total_supply(env, new_supply);
+
+    storage::increment_metrics(env, 0, 1, 0);
+}
+
 fn burn_balance(env: &Env, from: &Address, amount: i128) {
     let balance = storage::balance_of(env, from);
     if amount > balance {
@@ -214,6 +632,26 @@ fn burn_balance(env: &Env, from: &Address, amount: i128) {
     let supply = storage::total_supply(env);
     let new_supply = supply.checked_sub(amount).expect("Overflow");
     storage::set_total_supply(env, new_supply);
+
+    storage::increment_metrics(env, 0, 0, 1);
+}
+
+/// Burn the configured share of `amount` from `from`, then transfer the rest to `to`.
+fn transfer_with_burn(env: &Env, from: &Address, to: &Address, amount: i128) {
+    let burn_bps = storage::burn_on_transfer_bps(env);
+    let burned = if burn_bps == 0 || from == to {
+        0
+    } else {
+        amount.checked_mul(burn_bps as i128).expect("Overflow") / 10_000
+    };
+
+    if burned > 0 {
+        burn_balance(env, from, burned);
+        env.events()
+            .publish((Symbol::new(env, "burn"), from.clone()), burned);
+    }
+
+    internal_transfer(env, from, to, amount - burned);
 }
 
 fn internal_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
@@ -233,6 +671,7 @@ fn internal_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
 
     storage::set_balance(env, from, &new_from);
     storage::set_balance(env, to, &new_to);
+    storage::increment_metrics(env, 1, 0, 0);
 
     env.events()
         .publish((Symbol::new(env, "transfer"), from, to), amount);
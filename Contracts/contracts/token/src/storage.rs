@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, String};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -22,6 +22,24 @@ pub struct TokenMetadata {
     pub decimals: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ClawbackRecord {
+    pub from: Address,
+    pub amount: i128,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct MintMemoRecord {
+    pub to: Address,
+    pub amount: i128,
+    pub memo: String,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -30,6 +48,23 @@ pub enum DataKey {
     Balance(Address),
     Allowance(AllowanceKey),
     Authorized(Address),
+    RequireAuthorization,
+    Paused,
+    Minter(Address),
+    ClawbackCount(Address),
+    ClawbackRecord(Address, u32),
+    EmergencyRegistry,
+    MinAllowanceLedgers,
+    Metrics,
+    HaltCache,
+    MaxTransfer,
+    BurnOnTransferBps,
+    SnapshotId,
+    SnapshotSupply(u64),
+    SnapshotBalance(u64, Address),
+    SnapshotCheckpoints(Address),
+    MintMemoCount(Address),
+    MintMemo(Address, u32),
 }
 
 pub fn has_admin(env: &Env) -> bool {
@@ -77,6 +112,8 @@ pub fn balance_of(env: &Env, id: &Address) -> i128 {
 }
 
 pub fn set_balance(env: &Env, id: &Address, amount: &i128) {
+    checkpoint_balance(env, id);
+
     if *amount == 0 {
         env.storage().persistent().remove(&DataKey::Balance(id.clone()));
     } else {
@@ -86,6 +123,192 @@ pub fn set_balance(env: &Env, id: &Address, amount: &i128) {
     }
 }
 
+/// (transfers, mints, burns) operation counters, kept in a single instance entry.
+pub fn get_metrics(env: &Env) -> (u64, u64, u64) {
+    env.storage()
+        .instance()
+        .get(&DataKey::Metrics)
+        .unwrap_or((0, 0, 0))
+}
+
+pub fn increment_metrics(env: &Env, transfers: u64, mints: u64, burns: u64) {
+    let (total_transfers, total_mints, total_burns) = get_metrics(env);
+    env.storage().instance().set(
+        &DataKey::Metrics,
+        &(total_transfers + transfers, total_mints + mints, total_burns + burns),
+    );
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+pub fn set_emergency_registry(env: &Env, registry: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EmergencyRegistry, registry);
+}
+
+pub fn get_emergency_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::EmergencyRegistry)
+}
+
+/// Registry halt flag cached for the current ledger, if it was already fetched this ledger.
+pub fn get_cached_halt(env: &Env) -> Option<bool> {
+    let cached: Option<(u32, bool)> = env.storage().temporary().get(&DataKey::HaltCache);
+    match cached {
+        Some((sequence, halted)) if sequence == env.ledger().sequence() => Some(halted),
+        _ => None,
+    }
+}
+
+pub fn set_cached_halt(env: &Env, halted: bool) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::HaltCache, &(env.ledger().sequence(), halted));
+}
+
+pub fn clear_cached_halt(env: &Env) {
+    env.storage().temporary().remove(&DataKey::HaltCache);
+}
+
+pub fn set_max_transfer(env: &Env, max_transfer: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxTransfer, &max_transfer);
+}
+
+pub fn get_max_transfer(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&DataKey::MaxTransfer)
+}
+
+pub fn set_burn_on_transfer_bps(env: &Env, bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BurnOnTransferBps, &bps);
+}
+
+pub fn burn_on_transfer_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BurnOnTransferBps)
+        .unwrap_or(0)
+}
+
+pub fn is_minter(env: &Env, id: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Minter(id.clone()))
+}
+
+pub fn set_minter(env: &Env, id: &Address, enabled: bool) {
+    let key = DataKey::Minter(id.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Append a clawback audit record for `from` and return its index.
+pub fn push_clawback_record(env: &Env, from: &Address, record: &ClawbackRecord) -> u32 {
+    let count_key = DataKey::ClawbackCount(from.clone());
+    let index: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ClawbackRecord(from.clone(), index), record);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    index
+}
+
+pub fn get_clawback_record(env: &Env, from: &Address, index: u32) -> Option<ClawbackRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClawbackRecord(from.clone(), index))
+}
+
+/// Append a mint memo record for `to` and return its index.
+pub fn push_mint_memo(env: &Env, to: &Address, record: &MintMemoRecord) -> u32 {
+    let count_key = DataKey::MintMemoCount(to.clone());
+    let index: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MintMemo(to.clone(), index), record);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    index
+}
+
+pub fn get_mint_memo(env: &Env, to: &Address, index: u32) -> Option<MintMemoRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MintMemo(to.clone(), index))
+}
+
+pub fn snapshot_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SnapshotId)
+        .unwrap_or(0)
+}
+
+pub fn set_snapshot_id(env: &Env, id: u64) {
+    env.storage().instance().set(&DataKey::SnapshotId, &id);
+}
+
+pub fn set_snapshot_supply(env: &Env, id: u64, supply: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SnapshotSupply(id), &supply);
+}
+
+pub fn get_snapshot_supply(env: &Env, id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SnapshotSupply(id))
+        .expect("Snapshot not found")
+}
+
+pub fn get_snapshot_balance(env: &Env, id: u64, account: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SnapshotBalance(id, account.clone()))
+}
+
+/// Record the balance as of the latest snapshot before its first change after that snapshot.
+fn checkpoint_balance(env: &Env, id: &Address) {
+    let snapshot_id = snapshot_id(env);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let key = DataKey::SnapshotBalance(snapshot_id, id.clone());
+    if !env.storage().persistent().has(&key) {
+        let balance = balance_of(env, id);
+        env.storage().persistent().set(&key, &balance);
+
+        let mut checkpoints = snapshot_checkpoints(env, id);
+        checkpoints.push_back(snapshot_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SnapshotCheckpoints(id.clone()), &checkpoints);
+    }
+}
+
+/// Snapshot ids at which `id` has a recorded balance, in ascending order.
+pub fn snapshot_checkpoints(env: &Env, id: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SnapshotCheckpoints(id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
 pub fn set_allowance(env: &Env, from: &Address, spender: &Address, allowance: &Allowance) {
     let key = DataKey::Allowance(AllowanceKey {
         from: from.clone(),
@@ -105,6 +328,35 @@ pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> Allowance
     })
 }
 
+/// Remove the allowance entry if it exists and has expired; returns whether it was removed.
+pub fn remove_expired_allowance(env: &Env, from: &Address, spender: &Address) -> bool {
+    let key = DataKey::Allowance(AllowanceKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    let allowance: Option<Allowance> = env.storage().persistent().get(&key);
+    match allowance {
+        Some(allowance) if allowance.expiration_ledger < env.ledger().sequence() => {
+            env.storage().persistent().remove(&key);
+            true
+        }
+        _ => false,
+    }
+}
+
+pub fn set_min_allowance_ledgers(env: &Env, ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinAllowanceLedgers, &ledgers);
+}
+
+pub fn min_allowance_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinAllowanceLedgers)
+        .unwrap_or(0)
+}
+
 pub fn get_allowance_amount(env: &Env, from: &Address, spender: &Address) -> i128 {
     let allowance = get_allowance(env, from, spender);
     let current_ledger = env.ledger().sequence();
@@ -121,9 +373,23 @@ pub fn set_authorized(env: &Env, id: &Address, authorized: bool) {
         .set(&DataKey::Authorized(id.clone()), &authorized);
 }
 
+/// Unlisted accounts default to authorized unless the token was initialized as permissioned.
 pub fn get_authorized(env: &Env, id: &Address) -> bool {
     env.storage()
         .persistent()
         .get(&DataKey::Authorized(id.clone()))
-        .unwrap_or(true)
+        .unwrap_or(!require_authorization(env))
+}
+
+pub fn set_require_authorization(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RequireAuthorization, &required);
+}
+
+pub fn require_authorization(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireAuthorization)
+        .unwrap_or(false)
 }
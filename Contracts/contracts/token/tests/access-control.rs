@@ -1,6 +1,7 @@
 use soroban_sdk::{
-    contract, contractimpl, testutils::Address as _, testutils::Ledger as _, Address, Env,
-    IntoVal, Symbol,
+    contract, contractimpl,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal, Symbol,
 };
 use token::{TokenContract, TokenContractClient};
 
@@ -34,9 +35,10 @@ fn transfer_approve_allowance_and_metadata() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&owner, &1_000);
+    client.mint(&admin, &owner, &1_000);
 
     let current_ledger = env.ledger().sequence();
     client.approve(&owner, &spender, &250, &(current_ledger + 10));
@@ -70,9 +72,10 @@ fn transfer_hook_is_safe_and_records_when_supported() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&sender, &500);
+    client.mint(&admin, &sender, &500);
 
     let hook_address = env.register_contract(None, HookReceiver);
 
@@ -122,9 +125,10 @@ fn expired_allowance_treated_as_zero() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&owner, &100);
+    client.mint(&admin, &owner, &100);
 
     let current = env.ledger().sequence();
     client.approve(&owner, &spender, &80, &current);
@@ -154,11 +158,149 @@ fn unauthorized_account_cannot_spend() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&owner, &100);
+    client.mint(&admin, &owner, &100);
     client.set_authorized(&owner, &false);
 
     assert!(!client.authorized(&owner));
     assert_eq!(client.balance(&recipient), 0);
 }
+
+#[test]
+fn permissionless_token_allows_unlisted_accounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    assert!(client.authorized(&recipient));
+
+    client.mint(&admin, &owner, &100);
+    client.transfer(&owner, &recipient, &40);
+    assert_eq!(client.balance(&recipient), 40);
+}
+
+#[test]
+fn permissioned_token_transfers_between_allowed_accounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &true,
+    );
+
+    assert!(!client.authorized(&owner));
+    assert!(!client.authorized(&recipient));
+
+    client.set_authorized(&owner, &true);
+    client.set_authorized(&recipient, &true);
+
+    client.mint(&admin, &owner, &100);
+    client.transfer(&owner, &recipient, &40);
+    assert_eq!(client.balance(&recipient), 40);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn permissioned_token_rejects_unlisted_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &true,
+    );
+
+    client.set_authorized(&owner, &true);
+    client.mint(&admin, &owner, &100);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer(env.clone(), owner.clone(), recipient.clone(), 40);
+    });
+}
+
+#[test]
+fn non_admin_cannot_call_admin_functions() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let intruder = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    // Admin functions take no caller argument: the only signature they accept is the stored admin's
+    client.set_authorized(&intruder, &true);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_id.clone(),
+                    Symbol::new(&env, "set_authorized"),
+                    (intruder.clone(), true).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    // With only the admin's signature mocked, the call goes through
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "set_authorized",
+            args: (intruder.clone(), false).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.set_authorized(&intruder, &false);
+    assert!(!client.authorized(&intruder));
+}
@@ -0,0 +1,187 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, IntoVal, Symbol, TryIntoVal,
+};
+use token::{TokenContract, TokenContractClient};
+
+#[test]
+fn prune_removes_expired_allowance_and_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    let current = env.ledger().sequence();
+    client.approve(&owner, &spender, &80, &(current + 10));
+
+    // Live allowances are left alone
+    assert!(!client.prune_allowance(&owner, &spender));
+    assert_eq!(client.allowance(&owner, &spender), 80);
+
+    env.ledger().with_mut(|li| li.sequence_number = current + 11);
+    assert!(client.prune_allowance(&owner, &spender));
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(name, Symbol::new(&env, "allowance_expired"));
+
+    // The entry is gone, so there is nothing left to prune
+    assert!(!client.prune_allowance(&owner, &spender));
+    assert_eq!(client.allowance(&owner, &spender), 0);
+}
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+
+    (contract_id, client)
+}
+
+#[test]
+fn approve_accepts_expiration_at_minimum_gap() {
+    let env = Env::default();
+    let (_contract_id, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.set_min_allowance_ledgers(&100);
+    assert_eq!(client.min_allowance_ledgers(), 100);
+
+    let current = env.ledger().sequence();
+    client.approve(&owner, &spender, &80, &(current + 100));
+    assert_eq!(client.allowance(&owner, &spender), 80);
+
+    // Revoking with a zero amount is never blocked
+    client.approve(&owner, &spender, &0, &current);
+    assert_eq!(client.allowance(&owner, &spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "Expiration too soon")]
+fn approve_rejects_expiration_inside_minimum_gap() {
+    let env = Env::default();
+    let (contract_id, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.set_min_allowance_ledgers(&100);
+    let current = env.ledger().sequence();
+
+    env.as_contract(&contract_id, || {
+        TokenContract::approve(env.clone(), owner.clone(), spender.clone(), 80, current + 99);
+    });
+}
+
+fn expiring_setup(env: &Env) -> (Address, TokenContractClient<'static>, Address, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let owner = Address::generate(env);
+    let spender = Address::generate(env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+    client.mint(&admin, &owner, &100);
+
+    let current = env.ledger().sequence();
+    client.approve(&owner, &spender, &80, &(current + 5));
+
+    (contract_id, client, owner, spender, admin)
+}
+
+#[test]
+fn transfer_from_succeeds_on_expiration_ledger() {
+    let env = Env::default();
+    let (_contract_id, client, owner, spender, _admin) = expiring_setup(&env);
+    let recipient = Address::generate(&env);
+
+    let current = env.ledger().sequence();
+    env.ledger().with_mut(|li| li.sequence_number = current + 5);
+
+    client.transfer_from(&spender, &owner, &recipient, &30);
+    assert_eq!(client.balance(&recipient), 30);
+    assert_eq!(client.allowance(&owner, &spender), 50);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn transfer_from_after_expiration_fails_with_allowance_expired() {
+    let env = Env::default();
+    let (contract_id, _client, owner, spender, _admin) = expiring_setup(&env);
+    let recipient = Address::generate(&env);
+
+    let current = env.ledger().sequence();
+    env.ledger().with_mut(|li| li.sequence_number = current + 6);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer_from(env.clone(), spender.clone(), owner.clone(), recipient.clone(), 30);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn burn_from_after_expiration_fails_with_allowance_expired() {
+    let env = Env::default();
+    let (contract_id, _client, owner, spender, _admin) = expiring_setup(&env);
+
+    let current = env.ledger().sequence();
+    env.ledger().with_mut(|li| li.sequence_number = current + 6);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::burn_from(env.clone(), spender.clone(), owner.clone(), 30);
+    });
+}
+
+#[test]
+fn allowance_details_report_expired_entry_without_removing_it() {
+    let env = Env::default();
+    let (_contract_id, client, owner, spender, _admin) = expiring_setup(&env);
+
+    let current = env.ledger().sequence();
+    let details = client.get_allowance_details(&owner, &spender);
+    assert_eq!(details.amount, 80);
+    assert_eq!(details.expiration_ledger, current + 5);
+
+    env.ledger().with_mut(|li| li.sequence_number = current + 6);
+    assert_eq!(client.allowance(&owner, &spender), 0);
+
+    let expired = client.get_allowance_details(&owner, &spender);
+    assert_eq!(expired.amount, 80);
+    assert_eq!(expired.expiration_ledger, current + 5);
+
+    // The view left the entry in place for prune_allowance to reclaim
+    assert!(client.prune_allowance(&owner, &spender));
+    assert_eq!(client.get_allowance_details(&owner, &spender).amount, 0);
+}
@@ -0,0 +1,72 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    Address, Env, IntoVal, Symbol, TryIntoVal, Vec,
+};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &true,
+    );
+
+    (contract_id, client)
+}
+
+#[test]
+fn set_authorized_batch_authorizes_every_account() {
+    let env = Env::default();
+    let (_contract_id, client) = setup(&env);
+
+    let mut cohort = Vec::new(&env);
+    for _ in 0..5 {
+        cohort.push_back(Address::generate(&env));
+    }
+    for id in cohort.iter() {
+        assert!(!client.authorized(&id));
+    }
+
+    client.set_authorized_batch(&cohort, &true);
+
+    for id in cohort.iter() {
+        assert!(client.authorized(&id));
+    }
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(name, Symbol::new(&env, "set_authorized_batch"));
+    let (count, authorize): (u32, bool) = data.try_into_val(&env).unwrap();
+    assert_eq!(count, 5);
+    assert!(authorize);
+
+    let per_account = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+            name == Symbol::new(&env, "set_authorized")
+        })
+        .count();
+    assert_eq!(per_account, 5);
+}
+
+#[test]
+#[should_panic(expected = "Empty batch")]
+fn set_authorized_batch_rejects_empty_batch() {
+    let env = Env::default();
+    let (contract_id, _client) = setup(&env);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::set_authorized_batch(env.clone(), Vec::new(&env), true);
+    });
+}
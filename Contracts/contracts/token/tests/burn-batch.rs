@@ -0,0 +1,47 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+    client.mint(&admin, &alice, &1_000);
+    client.mint(&admin, &bob, &500);
+
+    (contract_id, client, alice, bob)
+}
+
+#[test]
+fn burn_batch_burns_from_every_account() {
+    let env = Env::default();
+    let (_contract_id, client, alice, bob) = setup(&env);
+
+    client.burn_batch(&vec![&env, alice.clone(), bob.clone()], &vec![&env, 400, 500]);
+
+    assert_eq!(client.balance(&alice), 600);
+    assert_eq!(client.balance(&bob), 0);
+    assert_eq!(client.total_supply(), 600);
+}
+
+#[test]
+#[should_panic(expected = "Length mismatch")]
+fn burn_batch_rejects_length_mismatch() {
+    let env = Env::default();
+    let (contract_id, _client, alice, bob) = setup(&env);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::burn_batch(env.clone(), vec![&env, alice.clone(), bob.clone()], vec![&env, 100]);
+    });
+}
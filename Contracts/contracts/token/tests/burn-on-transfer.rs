@@ -0,0 +1,72 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let owner = Address::generate(env);
+    let recipient = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+    client.mint(&admin, &owner, &10_000);
+
+    (contract_id, client, owner, recipient)
+}
+
+#[test]
+fn transfer_without_burn_rate_moves_full_amount() {
+    let env = Env::default();
+    let (_contract_id, client, owner, recipient) = setup(&env);
+
+    assert_eq!(client.burn_on_transfer_bps(), 0);
+
+    client.transfer(&owner, &recipient, &1_000);
+    assert_eq!(client.balance(&owner), 9_000);
+    assert_eq!(client.balance(&recipient), 1_000);
+    assert_eq!(client.total_supply(), 10_000);
+}
+
+#[test]
+fn transfer_burns_configured_share() {
+    let env = Env::default();
+    let (_contract_id, client, owner, recipient) = setup(&env);
+
+    // 2.5% of every transfer is burned
+    client.set_burn_on_transfer_bps(&250);
+
+    client.transfer(&owner, &recipient, &1_000);
+    assert_eq!(client.balance(&owner), 9_000);
+    assert_eq!(client.balance(&recipient), 975);
+    assert_eq!(client.total_supply(), 9_975);
+
+    let spender = Address::generate(&env);
+    client.approve(&owner, &spender, &2_000, &1_000);
+    client.transfer_from(&spender, &owner, &recipient, &2_000);
+    assert_eq!(client.allowance(&owner, &spender), 0);
+    assert_eq!(client.balance(&owner), 7_000);
+    assert_eq!(client.balance(&recipient), 2_925);
+    assert_eq!(client.total_supply(), 9_925);
+
+    let (transfers, _mints, burns) = client.get_metrics();
+    assert_eq!((transfers, burns), (2, 2));
+}
+
+#[test]
+#[should_panic(expected = "Invalid burn rate")]
+fn burn_rate_above_full_amount_is_rejected() {
+    let env = Env::default();
+    let (contract_id, _client, _owner, _recipient) = setup(&env);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::set_burn_on_transfer_bps(env.clone(), 10_001);
+    });
+}
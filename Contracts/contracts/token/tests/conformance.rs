@@ -18,9 +18,10 @@ fn standard_conformance_transfer_and_balance() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&owner, &1_000);
+    client.mint(&admin, &owner, &1_000);
     client.transfer(&owner, &recipient, &200);
 
     assert_eq!(client.balance(&owner), 800);
@@ -45,9 +46,10 @@ fn standard_conformance_approve_allowance_transfer_from() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&owner, &1_000);
+    client.mint(&admin, &owner, &1_000);
 
     let current = env.ledger().sequence();
     client.approve(&owner, &spender, &300, &(current + 5));
@@ -75,6 +77,7 @@ fn standard_conformance_metadata() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
     assert_eq!(client.name(), "Stellara Token".into_val(&env));
@@ -99,9 +102,10 @@ fn standard_conformance_expired_allowance_is_zero() {
         &"Stellara Token".into_val(&env),
         &"STLR".into_val(&env),
         &7,
+        &false,
     );
 
-    client.mint(&owner, &100);
+    client.mint(&admin, &owner, &100);
 
     let current = env.ledger().sequence();
     client.approve(&owner, &spender, &80, &(current + 1));
@@ -0,0 +1,71 @@
+use emergency_registry::{EmergencyRegistryContract, EmergencyRegistryContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, EmergencyRegistryContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let registry_id = env.register_contract(None, EmergencyRegistryContract);
+    let registry = EmergencyRegistryContractClient::new(env, &registry_id);
+
+    let admin = Address::generate(env);
+    let holder = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+    client.mint(&admin, &holder, &1_000);
+
+    registry.initialize(&admin);
+    client.set_emergency_registry(&registry_id);
+
+    (contract_id, client, registry, admin, holder)
+}
+
+fn next_ledger(env: &Env) {
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+}
+
+#[test]
+fn transfers_resume_after_global_halt_is_lifted() {
+    let env = Env::default();
+    let (_contract_id, client, registry, admin, holder) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    client.transfer(&holder, &recipient, &100);
+
+    registry.set_global_halt(&admin, &true);
+    next_ledger(&env);
+
+    // Admin can still recover funds during an incident
+    client.clawback(&admin, &holder, &100);
+    assert_eq!(client.balance(&holder), 800);
+
+    registry.set_global_halt(&admin, &false);
+    next_ledger(&env);
+
+    client.transfer(&holder, &recipient, &100);
+    assert_eq!(client.balance(&recipient), 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn transfer_fails_while_globally_halted() {
+    let env = Env::default();
+    let (contract_id, client, registry, admin, holder) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    registry.set_global_halt(&admin, &true);
+    next_ledger(&env);
+    assert!(!client.is_paused());
+
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer(env.clone(), holder.clone(), recipient.clone(), 100);
+    });
+}
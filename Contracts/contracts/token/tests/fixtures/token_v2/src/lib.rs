@@ -0,0 +1,30 @@
+//! Upgrade target for the token's upgrade tests.
+//!
+//! Rebuild with
+//! `RUSTFLAGS="-C target-cpu=mvp" cargo build --target wasm32-unknown-unknown --release`
+//! and copy the output to `tests/fixtures/token_v2.wasm`.
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+/// Mirrors the token's `DataKey::Balance`, so balances written by v1 stay readable
+#[contracttype]
+pub enum DataKey {
+    Balance(Address),
+}
+
+#[contract]
+pub struct TokenV2;
+
+#[contractimpl]
+impl TokenV2 {
+    pub fn version() -> u32 {
+        2
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(id))
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    Address, Env, IntoVal, String, Symbol, TryIntoVal,
+};
+use token::{TokenContract, TokenContractClient};
+
+fn initialize(env: &Env, symbol: &str, decimals: u32) -> TokenContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let admin = Address::generate(env);
+    env.as_contract(&contract_id, || {
+        TokenContract::initialize(
+            env.clone(),
+            admin.clone(),
+            "Stellara Token".into_val(env),
+            String::from_str(env, symbol),
+            decimals,
+            false,
+        );
+    });
+
+    TokenContractClient::new(env, &contract_id)
+}
+
+#[test]
+fn initialize_accepts_standard_decimals() {
+    let env = Env::default();
+    let client = initialize(&env, "STLR", 7);
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn initialize_rejects_decimals_above_max() {
+    let env = Env::default();
+    initialize(&env, "STLR", 19);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn initialize_rejects_empty_symbol() {
+    let env = Env::default();
+    initialize(&env, "", 7);
+}
+
+#[test]
+fn initialize_emits_initialized_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    let (event_contract, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(event_contract, contract_id);
+    let topic: Symbol = topics.first().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "initialized"));
+    let data: (Address, String, String, u32) = data.try_into_val(&env).unwrap();
+    assert_eq!(
+        data,
+        (
+            admin,
+            String::from_str(&env, "Stellara Token"),
+            String::from_str(&env, "STLR"),
+            7
+        )
+    );
+}
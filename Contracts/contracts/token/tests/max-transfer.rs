@@ -0,0 +1,97 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'static>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let owner = Address::generate(env);
+    let recipient = Address::generate(env);
+
+    client.initialize_with_max_transfer(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &100,
+        &false,
+    );
+    client.mint(&admin, &owner, &1_000);
+
+    (contract_id, client, owner, recipient)
+}
+
+#[test]
+fn transfer_at_cap_succeeds() {
+    let env = Env::default();
+    let (_contract_id, client, owner, recipient) = setup(&env);
+
+    assert_eq!(client.max_transfer(), Some(100));
+
+    client.transfer(&owner, &recipient, &100);
+    assert_eq!(client.balance(&recipient), 100);
+
+    let spender = Address::generate(&env);
+    client.approve(&owner, &spender, &500, &1_000);
+    client.transfer_from(&spender, &owner, &recipient, &100);
+    assert_eq!(client.balance(&recipient), 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn transfer_over_cap_is_rejected() {
+    let env = Env::default();
+    let (contract_id, _client, owner, recipient) = setup(&env);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer(env.clone(), owner.clone(), recipient.clone(), 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn transfer_from_over_cap_is_rejected() {
+    let env = Env::default();
+    let (contract_id, client, owner, recipient) = setup(&env);
+
+    let spender = Address::generate(&env);
+    client.approve(&owner, &spender, &500, &1_000);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer_from(
+            env.clone(),
+            spender.clone(),
+            owner.clone(),
+            recipient.clone(),
+            101,
+        );
+    });
+}
+
+#[test]
+fn uncapped_token_has_no_max_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+    assert_eq!(client.max_transfer(), None);
+
+    client.mint(&admin, &owner, &1_000);
+    client.transfer(&owner, &recipient, &1_000);
+    assert_eq!(client.balance(&recipient), 1_000);
+}
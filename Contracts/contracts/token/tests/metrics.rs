@@ -0,0 +1,37 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+#[test]
+fn metrics_count_transfers_mints_and_burns() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+    assert_eq!(client.get_metrics(), (0, 0, 0));
+
+    client.mint(&admin, &alice, &1_000);
+    client.mint(&admin, &bob, &500);
+
+    client.transfer(&alice, &bob, &100);
+    client.approve(&alice, &bob, &200, &(env.ledger().sequence() + 100));
+    client.transfer_from(&bob, &alice, &bob, &50);
+    // No-op transfers are not counted
+    client.transfer(&alice, &alice, &10);
+
+    client.burn(&bob, &25);
+    client.clawback(&admin, &alice, &25);
+
+    assert_eq!(client.get_metrics(), (2, 2, 2));
+}
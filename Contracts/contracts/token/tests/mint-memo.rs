@@ -0,0 +1,44 @@
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, IntoVal, String};
+use token::{TokenContract, TokenContractClient};
+
+#[test]
+fn memo_mints_are_recorded_per_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.mint_with_memo(&admin, &holder, &300, &String::from_str(&env, "airdrop-spring"));
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.mint_with_memo(&admin, &holder, &200, &String::from_str(&env, "airdrop-summer"));
+
+    assert_eq!(client.balance(&holder), 500);
+    assert_eq!(client.total_supply(), 500);
+
+    let first = client.get_mint_memo(&holder, &0).unwrap();
+    assert_eq!(first.to, holder);
+    assert_eq!(first.amount, 300);
+    assert_eq!(first.memo, String::from_str(&env, "airdrop-spring"));
+    assert_eq!(first.timestamp, 100);
+
+    let second = client.get_mint_memo(&holder, &1).unwrap();
+    assert_eq!(second.amount, 200);
+    assert_eq!(second.memo, String::from_str(&env, "airdrop-summer"));
+    assert_eq!(second.timestamp, 200);
+
+    assert!(client.get_mint_memo(&holder, &2).is_none());
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+
+    (contract_id, client, admin)
+}
+
+#[test]
+fn registered_minter_can_mint_and_clawback() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = setup(&env);
+
+    let bridge = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.add_minter(&bridge);
+    assert!(client.is_minter(&bridge));
+
+    client.mint(&bridge, &holder, &500);
+    client.clawback(&bridge, &holder, &200);
+    assert_eq!(client.balance(&holder), 300);
+
+    // The admin keeps its minting authority alongside registered minters
+    client.mint(&admin, &holder, &100);
+    assert_eq!(client.balance(&holder), 400);
+    assert_eq!(client.total_supply(), 400);
+}
+
+#[test]
+#[should_panic(expected = "Not a minter")]
+fn unregistered_address_cannot_mint() {
+    let env = Env::default();
+    let (contract_id, _client, _admin) = setup(&env);
+
+    let stranger = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::mint(env.clone(), stranger.clone(), stranger.clone(), 500);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Not a minter")]
+fn removed_minter_cannot_mint() {
+    let env = Env::default();
+    let (contract_id, client, _admin) = setup(&env);
+
+    let staking = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.add_minter(&staking);
+    client.mint(&staking, &holder, &100);
+
+    client.remove_minter(&staking);
+    assert!(!client.is_minter(&staking));
+
+    env.as_contract(&contract_id, || {
+        TokenContract::mint(env.clone(), staking.clone(), holder.clone(), 100);
+    });
+}
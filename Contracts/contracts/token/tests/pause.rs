@@ -0,0 +1,69 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let holder = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+    client.mint(&admin, &holder, &1_000);
+
+    (contract_id, client, admin, holder)
+}
+
+#[test]
+fn transfers_resume_after_unpause_and_clawback_works_while_paused() {
+    let env = Env::default();
+    let (_contract_id, client, admin, holder) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    client.set_paused(&true);
+    assert!(client.is_paused());
+
+    // Admin can still recover funds during an incident
+    client.clawback(&admin, &holder, &100);
+    assert_eq!(client.balance(&holder), 900);
+
+    client.set_paused(&false);
+    assert!(!client.is_paused());
+
+    client.transfer(&holder, &recipient, &300);
+    assert_eq!(client.balance(&recipient), 300);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn transfer_fails_while_paused() {
+    let env = Env::default();
+    let (contract_id, client, _admin, holder) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    client.set_paused(&true);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer(env.clone(), holder.clone(), recipient.clone(), 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn mint_fails_while_paused() {
+    let env = Env::default();
+    let (contract_id, client, admin, holder) = setup(&env);
+
+    client.set_paused(&true);
+
+    env.as_contract(&contract_id, || {
+        TokenContract::mint(env.clone(), admin.clone(), holder.clone(), 100);
+    });
+}
@@ -0,0 +1,109 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+#[test]
+fn snapshot_balance_differs_from_live_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    client.mint(&admin, &alice, &1_000);
+
+    let first = client.snapshot();
+    assert_eq!(first, 1);
+    assert_eq!(client.total_supply_at_snapshot(&first), 1_000);
+
+    client.transfer(&alice, &bob, &400);
+
+    let second = client.snapshot();
+    client.transfer(&alice, &bob, &100);
+    client.mint(&admin, &alice, &50);
+
+    // Live balances reflect every movement
+    assert_eq!(client.balance(&alice), 550);
+    assert_eq!(client.balance(&bob), 500);
+
+    // Snapshot balances are frozen at the time of each snapshot
+    assert_eq!(client.balance_at_snapshot(&first, &alice), 1_000);
+    assert_eq!(client.balance_at_snapshot(&first, &bob), 0);
+    assert_eq!(client.balance_at_snapshot(&second, &alice), 600);
+    assert_eq!(client.balance_at_snapshot(&second, &bob), 400);
+    assert_eq!(client.total_supply_at_snapshot(&second), 1_000);
+}
+
+#[test]
+fn untouched_account_reports_live_balance_at_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    client.mint(&admin, &holder, &300);
+    let id = client.snapshot();
+
+    assert_eq!(client.balance_at_snapshot(&id, &holder), 300);
+}
+
+#[test]
+fn snapshot_balance_skips_snapshots_without_changes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &false,
+    );
+
+    client.mint(&admin, &holder, &100);
+    let early = client.snapshot();
+    for _ in 0..20 {
+        client.snapshot();
+    }
+    let late = client.snapshot();
+    client.mint(&admin, &holder, &50);
+    for _ in 0..20 {
+        client.snapshot();
+    }
+    client.mint(&admin, &holder, &25);
+
+    // Both lookups resolve through the holder's checkpoint list
+    assert_eq!(client.balance_at_snapshot(&early, &holder), 100);
+    assert_eq!(client.balance_at_snapshot(&late, &holder), 100);
+    assert_eq!(client.balance_at_snapshot(&(late + 1), &holder), 150);
+    assert_eq!(client.balance(&holder), 175);
+}
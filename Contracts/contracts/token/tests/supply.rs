@@ -0,0 +1,57 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use token::{TokenContract, TokenContractClient};
+
+#[test]
+fn initialize_with_supply_mints_to_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+
+    client.initialize_with_supply(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &1_000_000,
+        &false,
+    );
+
+    assert_eq!(client.balance(&admin), 1_000_000);
+    assert_eq!(client.total_supply(), 1_000_000);
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn permissioned_initial_supply_can_be_distributed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize_with_supply(
+        &admin,
+        &"Stellara Token".into_val(&env),
+        &"STLR".into_val(&env),
+        &7,
+        &1_000_000,
+        &true,
+    );
+
+    // The admin is authorized from the start; recipients still need allowing
+    assert!(client.authorized(&admin));
+    assert!(!client.authorized(&holder));
+
+    client.set_authorized(&holder, &true);
+    client.transfer(&admin, &holder, &250_000);
+    assert_eq!(client.balance(&admin), 750_000);
+    assert_eq!(client.balance(&holder), 250_000);
+}
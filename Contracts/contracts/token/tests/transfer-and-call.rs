@@ -0,0 +1,83 @@
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, vec, Address, Env, Error, IntoVal, Symbol,
+};
+use token::{TokenContract, TokenContractClient};
+
+#[contract]
+struct StakingReceiver;
+
+#[contractimpl]
+impl StakingReceiver {
+    pub fn close(env: Env) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "closed"), &true);
+    }
+
+    // Fails by returning an error: a panic inside a native test contract aborts the test process
+    pub fn stake(env: Env, staker: Address, amount: i128) -> Result<i128, Error> {
+        if env.storage().instance().has(&Symbol::new(&env, "closed")) {
+            return Err(Error::from_contract_error(1));
+        }
+        let staked: i128 = env.storage().instance().get(&staker).unwrap_or(0);
+        env.storage().instance().set(&staker, &(staked + amount));
+        Ok(staked + amount)
+    }
+}
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let holder = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+    client.mint(&admin, &holder, &1_000);
+
+    (contract_id, client, holder)
+}
+
+#[test]
+fn transfer_and_call_transfers_then_invokes_receiver() {
+    let env = Env::default();
+    let (_contract_id, client, holder) = setup(&env);
+    let receiver = env.register_contract(None, StakingReceiver);
+
+    let args = vec![&env, holder.into_val(&env), 400_i128.into_val(&env)];
+    let result =
+        client.transfer_and_call(&holder, &receiver, &400, &Symbol::new(&env, "stake"), &args);
+
+    let staked: i128 = result.into_val(&env);
+    assert_eq!(staked, 400);
+    assert_eq!(client.balance(&receiver), 400);
+    assert_eq!(client.balance(&holder), 600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn transfer_and_call_fails_when_callback_fails() {
+    let env = Env::default();
+    let (contract_id, _client, holder) = setup(&env);
+    let receiver = env.register_contract(None, StakingReceiver);
+    StakingReceiverClient::new(&env, &receiver).close();
+
+    let args = vec![&env, holder.into_val(&env), 400_i128.into_val(&env)];
+    env.as_contract(&contract_id, || {
+        TokenContract::transfer_and_call(
+            env.clone(),
+            holder.clone(),
+            receiver.clone(),
+            400,
+            Symbol::new(&env, "stake"),
+            args,
+        );
+    });
+}
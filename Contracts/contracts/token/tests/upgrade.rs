@@ -0,0 +1,88 @@
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, MockAuth, MockAuthInvoke},
+    Address, BytesN, Env, IntoVal, Symbol, TryIntoVal,
+};
+use token::{TokenContract, TokenContractClient};
+
+// Upgrade target built from tests/fixtures/token_v2
+mod token_v2 {
+    soroban_sdk::contractimport!(file = "tests/fixtures/token_v2.wasm");
+}
+
+fn setup(env: &Env) -> (Address, TokenContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+        &false,
+    );
+
+    (contract_id, client, admin)
+}
+
+#[test]
+fn admin_can_upgrade() {
+    let env = Env::default();
+    let (contract_id, client, admin) = setup(&env);
+    let holder = Address::generate(&env);
+    client.mint(&admin, &holder, &500);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(token_v2::WASM);
+    client.upgrade(&wasm_hash);
+
+    let (event_contract, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(event_contract, contract_id);
+    let topic: Symbol = topics.first().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "upgraded"));
+    let upgraded_to: BytesN<32> = data.try_into_val(&env).unwrap();
+    assert_eq!(upgraded_to, wasm_hash);
+
+    // The new code now serves calls and still reads the balances written before the upgrade
+    let upgraded = token_v2::Client::new(&env, &contract_id);
+    assert_eq!(upgraded.version(), 2);
+    assert_eq!(upgraded.balance(&holder), 500);
+}
+
+#[test]
+fn non_admin_cannot_upgrade() {
+    let env = Env::default();
+    let (contract_id, client, admin) = setup(&env);
+    let upgraded = token_v2::Client::new(&env, &contract_id);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(token_v2::WASM);
+    let invoke = MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "upgrade",
+        args: (wasm_hash.clone(),).into_val(&env),
+        sub_invokes: &[],
+    };
+
+    // Nothing has been upgraded yet, so the v2 entrypoint does not exist
+    assert!(upgraded.try_version().is_err());
+
+    // A failed require_auth aborts native test contracts instead of returning an error,
+    // so check that upgrade will accept no signature but the stored admin's
+    env.mock_auths(&[MockAuth { address: &admin, invoke: &invoke }]);
+    client.upgrade(&wasm_hash);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin,
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_id.clone(),
+                    Symbol::new(&env, "upgrade"),
+                    (wasm_hash.clone(),).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(upgraded.version(), 2);
+}
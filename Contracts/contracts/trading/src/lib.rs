@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, symbol_short};
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, IntoVal, Symbol, symbol_short};
 use shared::fees::{FeeManager, FeeError};
 use shared::governance::{
     GovernanceManager, GovernanceRole, UpgradeProposal,
@@ -28,6 +28,16 @@ pub struct Trade {
     pub is_buy: bool,
 }
 
+/// What is being traded, as passed to trade_with_splits
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TradeOrder {
+    pub pair: Symbol,
+    pub amount: i128,
+    pub price: i128,
+    pub is_buy: bool,
+}
+
 /// Trading statistics
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -87,7 +97,7 @@ impl UpgradeableTradingContract {
         let mut roles = soroban_sdk::Map::new(&env);
 
         // Set admin role
-        roles.set(admin, GovernanceRole::Admin);
+        roles.set(admin.clone(), GovernanceRole::Admin);
 
         // Set approvers
         for approver in approvers.iter() {
@@ -95,7 +105,7 @@ impl UpgradeableTradingContract {
         }
 
         // Set executor
-        roles.set(executor, GovernanceRole::Executor);
+        roles.set(executor.clone(), GovernanceRole::Executor);
 
         env.storage().persistent().set(&roles_key, &roles);
 
@@ -112,6 +122,11 @@ impl UpgradeableTradingContract {
         let version_key = symbol_short!("ver");
         env.storage().persistent().set(&version_key, &CONTRACT_VERSION);
 
+        env.events().publish(
+            (Symbol::new(&env, "initialized"),),
+            (admin, executor, CONTRACT_VERSION),
+        );
+
         Ok(())
     }
 
@@ -130,19 +145,41 @@ impl UpgradeableTradingContract {
         trader.require_auth();
 
         // Verify not paused
-        let paused_key = symbol_short!("pause");
-        let is_paused: bool = env
-            .storage()
-            .persistent()
-            .get(&paused_key)
-            .unwrap_or(false);
+        Self::require_not_paused(&env);
 
-        if is_paused {
-            panic!("PAUSED");
+        let order = TradeOrder { pair, amount, price, is_buy };
+
+        // Exempt traders skip fee collection entirely
+        if Self::is_fee_exempt(env.clone(), trader.clone()) {
+            return Ok(Self::record_trade(&env, trader, order, fee_token, 0));
         }
 
+        // Apply the trader's academy badge discount, if an academy contract is configured
+        let discount_bps = Self::academy_discount(&env, &trader);
+
+        // When accumulating, hold the fee in the contract until the next sweep
+        let accumulate = Self::is_fee_accumulation_enabled(env.clone());
+        let fee_recipient = if accumulate {
+            env.current_contract_address()
+        } else {
+            fee_recipient
+        };
+
         // Collect fee first
-        FeeManager::collect_fee(&env, &fee_token, &trader, &fee_recipient, fee_amount)?;
+        let fee_amount = FeeManager::collect_fee_with_discount(
+            &env,
+            &fee_token,
+            &trader,
+            &fee_recipient,
+            fee_amount,
+            discount_bps,
+        )?;
+
+        if accumulate && fee_amount > 0 {
+            let fees_key = (symbol_short!("fees"), fee_token.clone());
+            let accumulated = Self::accumulated_fees(env.clone(), fee_token.clone());
+            env.storage().persistent().set(&fees_key, &(accumulated + fee_amount));
+        }
 
         // Emit fee collected event
         if fee_amount > 0 {
@@ -155,63 +192,56 @@ impl UpgradeableTradingContract {
             });
         }
 
-        // Create trade record
-        let stats_key = symbol_short!("stats");
-        let mut stats: TradeStats = env
-            .storage()
-            .persistent()
-            .get(&stats_key)
-            .unwrap_or(TradeStats {
-                total_trades: 0,
-                total_volume: 0,
-                last_trade_id: 0,
-            });
-
-        let trade_id = stats.last_trade_id + 1;
-        let timestamp = env.ledger().timestamp();
-        let trade = Trade {
-            id: trade_id,
-            trader: trader.clone(),
-            pair: pair.clone(),
-            amount,
-            price,
-            timestamp,
-            is_buy,
-        };
+        Ok(Self::record_trade(&env, trader, order, fee_token, fee_amount))
+    }
 
-        // Update stats
-        stats.total_trades += 1;
-        stats.total_volume += amount;
-        stats.last_trade_id = trade_id;
+    /// Execute a trade whose fee is split across several recipients.
+    ///
+    /// `split_bps` must sum to 10000; rounding dust goes to the first recipient.
+    pub fn trade_with_splits(
+        env: Env,
+        trader: Address,
+        order: TradeOrder,
+        fee_token: Address,
+        fee_amount: i128,
+        recipients: soroban_sdk::Vec<Address>,
+        split_bps: soroban_sdk::Vec<u32>,
+    ) -> Result<u64, FeeError> {
+        trader.require_auth();
 
-        // Store trade
-        let trades_key = symbol_short!("trades");
-        let mut trades: soroban_sdk::Vec<Trade> = env
-            .storage()
-            .persistent()
-            .get(&trades_key)
-            .unwrap_or_else(|| soroban_sdk::Vec::new(&env));
+        // Verify not paused
+        Self::require_not_paused(&env);
 
-        trades.push_back(trade);
+        if recipients.len() != split_bps.len() {
+            return Err(FeeError::InvalidSplit);
+        }
 
-        // Update persistent storage
-        env.storage().persistent().set(&trades_key, &trades);
-        env.storage().persistent().set(&stats_key, &stats);
+        // Exempt traders skip fee collection entirely
+        if Self::is_fee_exempt(env.clone(), trader.clone()) {
+            return Ok(Self::record_trade(&env, trader, order, fee_token, 0));
+        }
 
-        // Emit trade executed event
-        EventEmitter::trade_executed(&env, TradeExecutedEvent {
-            trade_id,
-            trader,
-            pair,
-            amount,
-            price,
-            is_buy,
-            fee_amount,
-            fee_token,
-            timestamp,
-        });
+        // Apply the trader's academy badge discount, if an academy contract is configured
+        let discount_bps = Self::academy_discount(&env, &trader);
+        let fee_amount = FeeManager::apply_discount(fee_amount, discount_bps)?;
+        let shares = FeeManager::split_fee(&env, fee_amount, &split_bps)?;
+
+        // One transfer per recipient
+        for (recipient, share) in recipients.iter().zip(shares.iter()) {
+            FeeManager::collect_fee(&env, &fee_token, &trader, &recipient, share)?;
+
+            if share > 0 {
+                EventEmitter::fee_collected(&env, FeeCollectedEvent {
+                    payer: trader.clone(),
+                    recipient,
+                    amount: share,
+                    token: fee_token.clone(),
+                    timestamp: env.ledger().timestamp(),
+                });
+            }
+        }
 
-        Ok(trade_id)
+        Ok(Self::record_trade(&env, trader, order, fee_token, fee_amount))
     }
 
     /// Get current contract version
@@ -299,6 +329,130 @@ impl UpgradeableTradingContract {
         Ok(())
     }
 
+    /// Also halt trading whenever the shared emergency registry reports a global halt (admin only)
+    pub fn set_emergency_registry(
+        env: Env,
+        admin: Address,
+        registry: Address,
+    ) -> Result<(), TradeError> {
+        Self::require_admin(&env, &admin)?;
+
+        let registry_key = symbol_short!("emrg_reg");
+        env.storage().persistent().set(&registry_key, &registry);
+        env.storage().temporary().remove(&symbol_short!("halt_c"));
+
+        Ok(())
+    }
+
+    /// Get the emergency registry trading is checked against, if any
+    pub fn get_emergency_registry(env: Env) -> Option<Address> {
+        let registry_key = symbol_short!("emrg_reg");
+        env.storage().persistent().get(&registry_key)
+    }
+
+    /// Set the academy-rewards contract used to discount trading fees (admin only)
+    pub fn set_academy_contract(
+        env: Env,
+        admin: Address,
+        academy: Address,
+    ) -> Result<(), TradeError> {
+        Self::require_admin(&env, &admin)?;
+
+        let academy_key = symbol_short!("academy");
+        env.storage().persistent().set(&academy_key, &academy);
+
+        Ok(())
+    }
+
+    /// Let `account` trade without paying fees, e.g. market makers or treasury accounts (admin only)
+    pub fn set_fee_exempt(
+        env: Env,
+        admin: Address,
+        account: Address,
+        exempt: bool,
+    ) -> Result<(), TradeError> {
+        Self::require_admin(&env, &admin)?;
+
+        let exempt_key = (symbol_short!("fee_ex"), account);
+        if exempt {
+            env.storage().persistent().set(&exempt_key, &true);
+        } else {
+            env.storage().persistent().remove(&exempt_key);
+        }
+
+        Ok(())
+    }
+
+    /// Check whether `account` trades fee-free
+    pub fn is_fee_exempt(env: Env, account: Address) -> bool {
+        let exempt_key = (symbol_short!("fee_ex"), account);
+        env.storage().persistent().has(&exempt_key)
+    }
+
+    /// Hold `trade` fees in the contract for batched sweeps instead of paying
+    /// `fee_recipient` on every trade (admin only). Split fees are always paid out directly.
+    pub fn set_fee_accumulation(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), TradeError> {
+        Self::require_admin(&env, &admin)?;
+
+        let accumulate_key = symbol_short!("fee_acc");
+        env.storage().persistent().set(&accumulate_key, &enabled);
+
+        Ok(())
+    }
+
+    /// Check whether trade fees are being accumulated
+    pub fn is_fee_accumulation_enabled(env: Env) -> bool {
+        let accumulate_key = symbol_short!("fee_acc");
+        env.storage()
+            .persistent()
+            .get(&accumulate_key)
+            .unwrap_or(false)
+    }
+
+    /// Get the fees held in the contract for `token` since the last sweep
+    pub fn accumulated_fees(env: Env, token: Address) -> i128 {
+        let fees_key = (symbol_short!("fees"), token);
+        env.storage()
+            .persistent()
+            .get(&fees_key)
+            .unwrap_or(0)
+    }
+
+    /// Send all accumulated fees in `token` to `to` in one transfer (admin only)
+    pub fn sweep_fees(
+        env: Env,
+        admin: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<i128, TradeError> {
+        Self::require_admin(&env, &admin)?;
+
+        let amount = Self::accumulated_fees(env.clone(), token.clone());
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let fees_key = (symbol_short!("fees"), token.clone());
+        env.storage().persistent().remove(&fees_key);
+
+        let contract = env.current_contract_address();
+        soroban_sdk::token::Client::new(&env, &token).transfer(&contract, &to, &amount);
+
+        EventEmitter::fee_collected(&env, FeeCollectedEvent {
+            payer: contract,
+            recipient: to,
+            amount,
+            token,
+            timestamp: env.ledger().timestamp(),
+        });
+
+        Ok(amount)
+    }
+
     /// Propose an upgrade via governance
     pub fn propose_upgrade(
         env: Env,
@@ -381,6 +535,160 @@ impl UpgradeableTradingContract {
         GovernanceManager::cancel_proposal(&env, proposal_id, admin)
             .map_err(|_| TradeError::Unauthorized)
     }
+
+    /// Internal helper: panic if trading is paused locally or globally halted
+    fn require_not_paused(env: &Env) {
+        let paused_key = symbol_short!("pause");
+        let is_paused: bool = env
+            .storage()
+            .persistent()
+            .get(&paused_key)
+            .unwrap_or(false);
+
+        if is_paused {
+            panic!("PAUSED");
+        }
+
+        // Also halt while the shared emergency registry reports a global halt
+        if Self::is_globally_halted(env) {
+            panic!("GLOBALLY_HALTED");
+        }
+    }
+
+    /// Internal helper: whether the configured emergency registry reports a global halt,
+    /// queried at most once per ledger
+    fn is_globally_halted(env: &Env) -> bool {
+        let registry_key = symbol_short!("emrg_reg");
+        let registry: Address = match env.storage().persistent().get(&registry_key) {
+            Some(registry) => registry,
+            None => return false,
+        };
+
+        let cache_key = symbol_short!("halt_c");
+        if let Some((sequence, halted)) = env.storage().temporary().get::<_, (u32, bool)>(&cache_key) {
+            if sequence == env.ledger().sequence() {
+                return halted;
+            }
+        }
+
+        let halted: bool = env.invoke_contract(&registry, &Symbol::new(env, "is_halted"), soroban_sdk::Vec::new(env));
+        env.storage().temporary().set(&cache_key, &(env.ledger().sequence(), halted));
+        halted
+    }
+
+    /// Internal helper: store a trade, update stats and emit the trade event
+    fn record_trade(
+        env: &Env,
+        trader: Address,
+        order: TradeOrder,
+        fee_token: Address,
+        fee_amount: i128,
+    ) -> u64 {
+        let TradeOrder { pair, amount, price, is_buy } = order;
+
+        // Create trade record
+        let stats_key = symbol_short!("stats");
+        let mut stats: TradeStats = env
+            .storage()
+            .persistent()
+            .get(&stats_key)
+            .unwrap_or(TradeStats {
+                total_trades: 0,
+                total_volume: 0,
+                last_trade_id: 0,
+            });
+
+        let trade_id = stats.last_trade_id + 1;
+        let timestamp = env.ledger().timestamp();
+        let trade = Trade {
+            id: trade_id,
+            trader: trader.clone(),
+            pair: pair.clone(),
+            amount,
+            price,
+            timestamp,
+            is_buy,
+        };
+
+        // Update stats
+        stats.total_trades += 1;
+        stats.total_volume += amount;
+        stats.last_trade_id = trade_id;
+
+        // Store trade
+        let trades_key = symbol_short!("trades");
+        let mut trades: soroban_sdk::Vec<Trade> = env
+            .storage()
+            .persistent()
+            .get(&trades_key)
+            .unwrap_or_else(|| soroban_sdk::Vec::new(env));
+
+        trades.push_back(trade);
+
+        // Update persistent storage
+        env.storage().persistent().set(&trades_key, &trades);
+        env.storage().persistent().set(&stats_key, &stats);
+
+        // Emit trade executed event
+        let fee_exempt = Self::is_fee_exempt(env.clone(), trader.clone());
+        EventEmitter::trade_executed(env, TradeExecutedEvent {
+            trade_id,
+            trader,
+            pair,
+            amount,
+            price,
+            is_buy,
+            fee_amount,
+            fee_token,
+            fee_exempt,
+            timestamp,
+        });
+
+        trade_id
+    }
+
+    /// Internal helper: verify the caller holds the admin role
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), TradeError> {
+        admin.require_auth();
+
+        let roles_key = symbol_short!("roles");
+        let roles: soroban_sdk::Map<Address, GovernanceRole> = env
+            .storage()
+            .persistent()
+            .get(&roles_key)
+            .ok_or(TradeError::Unauthorized)?;
+
+        let role = roles
+            .get(admin.clone())
+            .ok_or(TradeError::Unauthorized)?;
+
+        if role != GovernanceRole::Admin {
+            return Err(TradeError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    /// Internal helper: look up the trader's fee discount from the academy contract,
+    /// capped at 100% and falling back to 0 if the lookup fails
+    fn academy_discount(env: &Env, trader: &Address) -> u32 {
+        let academy_key = symbol_short!("academy");
+        let academy: Option<Address> = env.storage().persistent().get(&academy_key);
+
+        let discount = match academy {
+            Some(academy) => match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+                &academy,
+                &Symbol::new(env, "get_user_discount"),
+                vec![env, trader.into_val(env)],
+            ) {
+                Ok(Ok(discount)) => discount,
+                _ => 0,
+            },
+            None => 0,
+        };
+
+        discount.min(10000)
+    }
 }
 
 #[cfg(test)]
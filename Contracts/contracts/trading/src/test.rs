@@ -40,6 +40,25 @@ fn setup_fee_token(env: &Env) -> (Address, token::Client<'_>, token::StellarAsse
     (token_id, token_client, token_admin)
 }
 
+/// Stand-in for the academy-rewards contract's discount lookup; the lookup
+/// fails until a discount is set
+#[contract]
+struct MockAcademy;
+
+#[contractimpl]
+impl MockAcademy {
+    pub fn set_discount(env: Env, discount_bps: u32) {
+        env.storage().instance().set(&symbol_short!("discount"), &discount_bps);
+    }
+
+    pub fn get_user_discount(env: Env, _user: Address) -> Result<u32, soroban_sdk::Error> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("discount"))
+            .ok_or(soroban_sdk::Error::from_contract_error(1))
+    }
+}
+
 fn set_timestamp(env: &Env, timestamp: u64) {
     let mut ledger_info = env.ledger().get();
     ledger_info.timestamp = timestamp;
@@ -65,6 +84,24 @@ fn test_init_and_getters() {
     assert_eq!(stats.last_trade_id, 0);
 }
 
+#[test]
+fn test_init_emits_initialized_event() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (event_contract, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(event_contract, contract_id);
+    let topic: Symbol = topics.first().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "initialized"));
+    let data: (Address, Address, u32) = data.try_into_val(&env).unwrap();
+    assert_eq!(data, (admin, executor, 1));
+}
+
 #[test]
 fn test_init_twice_fails() {
     let _guard = serial_lock();
@@ -115,6 +152,226 @@ fn test_trade_happy_path_updates_stats_and_transfers_fee() {
     assert_eq!(stats.last_trade_id, 1);
 }
 
+#[test]
+fn test_accumulated_fees_are_swept_to_treasury() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    token_admin.mint(&trader, &1000);
+    client.set_fee_accumulation(&admin, &true);
+
+    let pair = Symbol::new(&env, "XLMUSDC");
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(client.accumulated_fees(&token_id), 100);
+
+    client.trade(&trader, &pair, &250, &10, &false, &token_id, &50, &fee_recipient);
+    assert_eq!(client.accumulated_fees(&token_id), 150);
+    assert_eq!(token_client.balance(&contract_id), 150);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+
+    // Only the admin can sweep
+    let stranger = Address::generate(&env);
+    let result = client.try_sweep_fees(&stranger, &token_id, &treasury);
+    assert_eq!(result, Err(Ok(TradeError::Unauthorized)));
+
+    assert_eq!(client.sweep_fees(&admin, &token_id, &treasury), 150);
+    assert_eq!(token_client.balance(&treasury), 150);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.accumulated_fees(&token_id), 0);
+}
+
+#[test]
+fn test_fee_exempt_trader_pays_nothing() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let market_maker = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+    token_admin.mint(&market_maker, &1000);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_set_fee_exempt(&stranger, &market_maker, &true);
+    assert_eq!(result, Err(Ok(TradeError::Unauthorized)));
+
+    client.set_fee_exempt(&admin, &market_maker, &true);
+    assert!(client.is_fee_exempt(&market_maker));
+    assert!(!client.is_fee_exempt(&trader));
+
+    let pair = Symbol::new(&env, "XLMUSDC");
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&trader), 900);
+    assert_eq!(token_client.balance(&fee_recipient), 100);
+
+    client.trade(&market_maker, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&market_maker), 1000);
+    assert_eq!(token_client.balance(&fee_recipient), 100);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: TradeExecutedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.trader, market_maker);
+    assert!(event.fee_exempt);
+    assert_eq!(event.fee_amount, 0);
+
+    client.set_fee_exempt(&admin, &market_maker, &false);
+    client.trade(&market_maker, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&market_maker), 900);
+}
+
+#[test]
+fn test_trade_applies_academy_badge_discount() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let academy_id = env.register_contract(None, MockAcademy);
+    MockAcademyClient::new(&env, &academy_id).set_discount(&500);
+    client.set_academy_contract(&admin, &academy_id);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+
+    client.trade(
+        &trader,
+        &Symbol::new(&env, "XLMUSDC"),
+        &250,
+        &10,
+        &true,
+        &token_id,
+        &100,
+        &fee_recipient,
+    );
+
+    // 500 bps badge: trader pays 95% of the base fee
+    assert_eq!(token_client.balance(&trader), 905);
+    assert_eq!(token_client.balance(&fee_recipient), 95);
+}
+
+#[test]
+fn test_trade_discount_is_capped_and_lookup_failures_charge_full_fee() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+    let pair = Symbol::new(&env, "XLMUSDC");
+
+    // A failing lookup charges the full fee instead of blocking the trade
+    let academy_id = env.register_contract(None, MockAcademy);
+    client.set_academy_contract(&admin, &academy_id);
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&trader), 900);
+    assert_eq!(token_client.balance(&fee_recipient), 100);
+
+    // An out-of-range discount is capped at 100%, so the trade is free
+    MockAcademyClient::new(&env, &academy_id).set_discount(&20000);
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&trader), 900);
+    assert_eq!(token_client.balance(&fee_recipient), 100);
+}
+
+#[test]
+fn test_trade_with_splits_distributes_fee() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let insurance = Address::generate(&env);
+    let lp_sink = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(treasury.clone());
+    recipients.push_back(insurance.clone());
+    recipients.push_back(lp_sink.clone());
+    let mut split_bps = Vec::new(&env);
+    split_bps.push_back(6000);
+    split_bps.push_back(3000);
+    split_bps.push_back(1000);
+
+    // 101 does not divide evenly: 60 + 30 + 10 = 100, dust of 1 goes to the treasury
+    let trade_id = client.trade_with_splits(
+        &trader,
+        &TradeOrder { pair: Symbol::new(&env, "XLMUSDC"), amount: 250, price: 10, is_buy: true },
+        &token_id,
+        &101,
+        &recipients,
+        &split_bps,
+    );
+
+    assert_eq!(trade_id, 1);
+    assert_eq!(token_client.balance(&treasury), 61);
+    assert_eq!(token_client.balance(&insurance), 30);
+    assert_eq!(token_client.balance(&lp_sink), 10);
+    assert_eq!(token_client.balance(&trader), 899);
+    assert_eq!(client.get_stats().total_trades, 1);
+}
+
+#[test]
+fn test_trade_with_splits_rejects_bad_ratios() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(Address::generate(&env));
+    recipients.push_back(Address::generate(&env));
+    let mut split_bps = Vec::new(&env);
+    split_bps.push_back(6000);
+    split_bps.push_back(3000);
+
+    let result = client.try_trade_with_splits(
+        &trader,
+        &TradeOrder { pair: Symbol::new(&env, "XLMUSDC"), amount: 250, price: 10, is_buy: true },
+        &token_id,
+        &100,
+        &recipients,
+        &split_bps,
+    );
+
+    assert_eq!(result, Err(Ok(FeeError::InvalidSplit)));
+    assert_eq!(token_client.balance(&trader), 1000);
+}
+
 #[test]
 fn test_trade_invalid_fee_amount_fails() {
     let _guard = serial_lock();
@@ -193,6 +450,76 @@ fn test_pause_sets_flag() {
     assert!(!paused);
 }
 
+fn setup_halt_registry(env: &Env, client: &UpgradeableTradingContractClient, admin: &Address) -> emergency_registry::EmergencyRegistryContractClient<'static> {
+    let registry_id = env.register_contract(None, emergency_registry::EmergencyRegistryContract);
+    let registry = emergency_registry::EmergencyRegistryContractClient::new(env, &registry_id);
+    registry.initialize(admin);
+    client.set_emergency_registry(admin, &registry_id);
+    assert_eq!(client.get_emergency_registry(), Some(registry_id));
+    registry
+}
+
+#[test]
+fn test_trading_resumes_after_global_halt_is_lifted() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+    let registry = setup_halt_registry(&env, &client, &admin);
+
+    let (token_id, token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+
+    let pair = Symbol::new(&env, "XLMUSDC");
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+
+    // The registry is queried once per ledger, so a halt raised mid-ledger applies from the next one
+    registry.set_global_halt(&admin, &true);
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&fee_recipient), 200);
+
+    registry.set_global_halt(&admin, &false);
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    client.trade(&trader, &pair, &250, &10, &true, &token_id, &100, &fee_recipient);
+    assert_eq!(token_client.balance(&fee_recipient), 300);
+}
+
+#[test]
+#[should_panic(expected = "GLOBALLY_HALTED")]
+fn test_trade_fails_while_globally_halted() {
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+    let registry = setup_halt_registry(&env, &client, &admin);
+
+    let (token_id, _token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    token_admin.mint(&trader, &1000);
+
+    registry.set_global_halt(&admin, &true);
+
+    let _ = env.as_contract(&contract_id, || {
+        UpgradeableTradingContract::trade(
+            env.clone(),
+            trader.clone(),
+            Symbol::new(&env, "XLMUSDC"),
+            250,
+            10,
+            true,
+            token_id.clone(),
+            100,
+            fee_recipient.clone(),
+        )
+    });
+}
+
 #[test]
 fn test_pause_unpause_authorization() {
     let _guard = serial_lock();
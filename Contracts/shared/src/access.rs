@@ -0,0 +1,59 @@
+use soroban_sdk::{contracterror, symbol_short, Address, Env, Symbol};
+
+/// Role granted to the account passed to `Access::init`
+pub const ADMIN: Symbol = symbol_short!("ADMIN");
+/// Conventional role for accounts allowed to mint
+pub const MINTER: Symbol = symbol_short!("MINTER");
+/// Conventional role for accounts allowed to pause
+pub const PAUSER: Symbol = symbol_short!("PAUSER");
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccessError {
+    Unauthorized = 1201,
+}
+
+/// Role membership stored in persistent storage, keyed by `(role, account)`.
+///
+/// `set_role` and `revoke_role` do not check the caller; gate them behind
+/// `require_role(env, ADMIN, caller)` (or another role) in the contract.
+pub struct Access;
+
+impl Access {
+    /// Grants `ADMIN` to `admin`. Call once from the contract's initializer.
+    pub fn init(env: &Env, admin: &Address) {
+        Self::set_role(env, ADMIN, admin);
+    }
+
+    /// Grants `role` to `account`.
+    pub fn set_role(env: &Env, role: Symbol, account: &Address) {
+        let role_key = (symbol_short!("role"), role, account.clone());
+        env.storage().persistent().set(&role_key, &true);
+    }
+
+    /// Removes `role` from `account`.
+    pub fn revoke_role(env: &Env, role: Symbol, account: &Address) {
+        let role_key = (symbol_short!("role"), role, account.clone());
+        env.storage().persistent().remove(&role_key);
+    }
+
+    /// Returns whether `account` holds `role`.
+    pub fn has_role(env: &Env, role: Symbol, account: &Address) -> bool {
+        let role_key = (symbol_short!("role"), role, account.clone());
+        env.storage().persistent().has(&role_key)
+    }
+
+    /// Requires `caller` to authorize the call and hold `role`.
+    ///
+    /// # Returns
+    /// * `Result<(), AccessError>` - `Err(AccessError::Unauthorized)` if the role is missing
+    pub fn require_role(env: &Env, role: Symbol, caller: &Address) -> Result<(), AccessError> {
+        caller.require_auth();
+
+        if !Self::has_role(env, role, caller) {
+            return Err(AccessError::Unauthorized);
+        }
+        Ok(())
+    }
+}
@@ -61,6 +61,8 @@ pub struct TradeExecutedEvent {
     pub fee_amount: i128,
     /// Token used for fee payment
     pub fee_token: Address,
+    /// Whether the trader was exempt from fees
+    pub fee_exempt: bool,
     /// Block timestamp when trade occurred
     pub timestamp: u64,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, Address, Env, token};
+use soroban_sdk::{contracterror, symbol_short, Address, Env, Vec, token};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -6,6 +6,9 @@ use soroban_sdk::{contracterror, Address, Env, token};
 pub enum FeeError {
     InsufficientBalance = 1001,
     InvalidAmount = 1002,
+    InvalidDiscount = 1003,
+    InvalidSplit = 1004,
+    InvalidFeeRate = 1005,
 }
 
 pub struct FeeManager;
@@ -51,4 +54,166 @@ impl FeeManager {
 
         Ok(())
     }
+
+    /// Collects a fee after applying a basis-point discount.
+    ///
+    /// The charged amount is `base_fee * (10000 - discount_bps) / 10000`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `token` - The token contract address to pay fees in
+    /// * `payer` - The address paying the fee
+    /// * `destination` - The address receiving the fee
+    /// * `base_fee` - The undiscounted fee amount
+    /// * `discount_bps` - The discount in basis points (max 10000)
+    ///
+    /// # Returns
+    /// * `Result<i128, FeeError>` - The amount actually charged
+    pub fn collect_fee_with_discount(
+        env: &Env,
+        token: &Address,
+        payer: &Address,
+        destination: &Address,
+        base_fee: i128,
+        discount_bps: u32,
+    ) -> Result<i128, FeeError> {
+        let charged = Self::apply_discount(base_fee, discount_bps)?;
+        Self::collect_fee(env, token, payer, destination, charged)?;
+
+        Ok(charged)
+    }
+
+    /// Collects a basis-point fee of a trade notional, clamped to the configured bounds.
+    ///
+    /// The charged amount is `notional * fee_bps / 10000`, raised to the minimum fee and
+    /// capped at the maximum fee set with `set_fee_bounds`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `token` - The token contract address to pay fees in
+    /// * `from` - The address paying the fee
+    /// * `recipient` - The address receiving the fee
+    /// * `notional` - The trade notional the fee is a share of
+    /// * `fee_bps` - The fee rate in basis points (max 10000)
+    ///
+    /// # Returns
+    /// * `Result<i128, FeeError>` - The amount actually charged
+    pub fn collect_percentage_fee(
+        env: &Env,
+        token: &Address,
+        from: &Address,
+        recipient: &Address,
+        notional: i128,
+        fee_bps: u32,
+    ) -> Result<i128, FeeError> {
+        if fee_bps > 10000 {
+            return Err(FeeError::InvalidFeeRate);
+        }
+        if notional < 0 {
+            return Err(FeeError::InvalidAmount);
+        }
+
+        let fee = notional
+            .checked_mul(fee_bps as i128)
+            .ok_or(FeeError::InvalidAmount)?
+            / 10000;
+        let (min_fee, max_fee) = Self::get_fee_bounds(env);
+        let charged = fee.max(min_fee).min(max_fee);
+
+        Self::collect_fee(env, token, from, recipient, charged)?;
+
+        Ok(charged)
+    }
+
+    /// Stores the minimum and maximum fee used by `collect_percentage_fee`.
+    ///
+    /// Bounds live in the calling contract's instance storage. Role checks stay with
+    /// the caller.
+    ///
+    /// # Returns
+    /// * `Result<(), FeeError>` - `Err(FeeError::InvalidAmount)` unless `0 <= min_fee <= max_fee`
+    pub fn set_fee_bounds(env: &Env, min_fee: i128, max_fee: i128) -> Result<(), FeeError> {
+        if min_fee < 0 || min_fee > max_fee {
+            return Err(FeeError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("fee_bnds"), &(min_fee, max_fee));
+
+        Ok(())
+    }
+
+    /// Returns the `(min_fee, max_fee)` bounds, `(0, i128::MAX)` if never set.
+    pub fn get_fee_bounds(env: &Env) -> (i128, i128) {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("fee_bnds"))
+            .unwrap_or((0, i128::MAX))
+    }
+
+    /// Applies a basis-point discount to a fee without transferring anything.
+    ///
+    /// # Returns
+    /// * `Result<i128, FeeError>` - `base_fee * (10000 - discount_bps) / 10000`, or
+    ///   `Err(FeeError::InvalidAmount)` if that product overflows
+    pub fn apply_discount(base_fee: i128, discount_bps: u32) -> Result<i128, FeeError> {
+        if discount_bps > 10000 {
+            return Err(FeeError::InvalidDiscount);
+        }
+        if base_fee < 0 {
+            return Err(FeeError::InvalidAmount);
+        }
+
+        let discounted = base_fee
+            .checked_mul(10000 - discount_bps as i128)
+            .ok_or(FeeError::InvalidAmount)?;
+
+        Ok(discounted / 10000)
+    }
+
+    /// Splits a fee into per-recipient shares by basis points.
+    ///
+    /// The ratios must sum to exactly 10000. Rounding dust is assigned to the
+    /// first share so the shares always add up to `amount`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `amount` - The total fee to split
+    /// * `split_bps` - The share of each recipient in basis points
+    ///
+    /// # Returns
+    /// * `Result<Vec<i128>, FeeError>` - One share per entry in `split_bps`, or
+    ///   `Err(FeeError::InvalidAmount)` if a share overflows
+    pub fn split_fee(env: &Env, amount: i128, split_bps: &Vec<u32>) -> Result<Vec<i128>, FeeError> {
+        if amount < 0 {
+            return Err(FeeError::InvalidAmount);
+        }
+        if split_bps.is_empty() {
+            return Err(FeeError::InvalidSplit);
+        }
+
+        let total_bps: u64 = split_bps.iter().map(|bps| bps as u64).sum();
+        if total_bps != 10000 {
+            return Err(FeeError::InvalidSplit);
+        }
+
+        let mut shares = Vec::new(env);
+        let mut distributed: i128 = 0;
+        for bps in split_bps.iter() {
+            let share = amount
+                .checked_mul(bps as i128)
+                .ok_or(FeeError::InvalidAmount)?
+                / 10000;
+            distributed += share;
+            shares.push_back(share);
+        }
+
+        // Assign rounding dust to the first recipient
+        let dust = amount - distributed;
+        let first = shares.get(0).unwrap_or(0);
+        shares.set(0, first + dust);
+
+        Ok(shares)
+    }
 }
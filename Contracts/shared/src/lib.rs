@@ -10,9 +10,12 @@ pub struct ContractConfig {
     pub is_paused: bool,
 }
 
+pub mod access;
 pub mod events;
 pub mod fees;
 pub mod governance;
+pub mod pausable;
+pub mod safe_call;
 
 /// Standard contract error codes
 pub mod errors {
@@ -0,0 +1,56 @@
+use soroban_sdk::{contracterror, symbol_short, Address, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PausableError {
+    Paused = 1101,
+}
+
+/// Common pause switch stored under a single instance key.
+///
+/// Contracts delegate to this module instead of keeping their own `Paused` key, so every
+/// contract pauses the same way. Role checks stay with the caller: verify that `admin`
+/// is allowed to pause before calling `set_paused`.
+pub struct Pausable;
+
+impl Pausable {
+    /// Sets the pause flag and emits a `paused` or `unpaused` event.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `admin` - The address toggling the flag; must authorize the call
+    /// * `paused` - Whether the contract should be paused
+    pub fn set_paused(env: &Env, admin: &Address, paused: bool) {
+        admin.require_auth();
+
+        env.storage().instance().set(&symbol_short!("paused"), &paused);
+
+        let topic = if paused {
+            symbol_short!("paused")
+        } else {
+            symbol_short!("unpaused")
+        };
+        env.events()
+            .publish((topic,), (admin.clone(), env.ledger().timestamp()));
+    }
+
+    /// Returns whether the contract is paused (false if never set).
+    pub fn is_paused(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("paused"))
+            .unwrap_or(false)
+    }
+
+    /// Guard for state-changing entry points.
+    ///
+    /// # Returns
+    /// * `Result<(), PausableError>` - `Err(PausableError::Paused)` while paused
+    pub fn require_not_paused(env: &Env) -> Result<(), PausableError> {
+        if Self::is_paused(env) {
+            return Err(PausableError::Paused);
+        }
+        Ok(())
+    }
+}
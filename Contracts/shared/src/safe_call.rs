@@ -29,22 +29,49 @@ pub fn safe_invoke(
     // invoke_contract_try returns Result<Val, Error>
     // We map generic errors to our specific codes if needed, or propagate.
     
-    // Explicitly type the result to see what compiler thinks
-    let res: Result<Val, Error> = env.try_invoke_contract(contract, func, args);
+    let res = env.try_invoke_contract::<Val, Error>(contract, func, args);
 
     match res {
-        Ok(val) => Ok(val),
-        Err(e) => {
+        Ok(Ok(val)) => Ok(val),
+        _ => {
             // Log the error for debugging
             // env.events().publish((Symbol::new(env, "call_failed"),), e);
             
-            // In a real module we might inspect `e` to see if it's a missing contract vs logic error.
+            // In a real module we might inspect the error to see if it's a missing contract vs logic error.
             // For now, we wrap it.
             Err(errors::CALL_FAILED)
         }
     }
 }
 
+/// Calls `safe_invoke` up to `max_attempts` times, returning the first success.
+///
+/// Soroban has no sleep, so retries run back-to-back within the same
+/// transaction. Only use this for idempotent calls: a failed attempt is
+/// rolled back, but a call that fails after an external side effect must be
+/// safe to repeat.
+///
+/// # Returns
+/// * `Result<Val, u32>` - The first successful return value, or the last error code
+pub fn safe_invoke_retry(
+    env: &Env,
+    contract: &Address,
+    func: &Symbol,
+    args: Vec<Val>,
+    max_attempts: u32,
+) -> Result<Val, u32> {
+    let mut last_error = errors::CALL_FAILED;
+
+    for _ in 0..max_attempts {
+        match safe_invoke(env, contract, func, args.clone()) {
+            Ok(val) => return Ok(val),
+            Err(code) => last_error = code,
+        }
+    }
+
+    Err(last_error)
+}
+
 /// Verifies a contract address is valid (basic check).
 pub fn verify_target(_env: &Env, _contract: &Address) -> bool {
     // This is a placeholder. In Soroban, an Address is just a handle.
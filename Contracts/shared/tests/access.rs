@@ -0,0 +1,60 @@
+use shared::access::{Access, AccessError, ADMIN, MINTER};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, Symbol};
+
+#[contract]
+struct HostContract;
+
+#[contractimpl]
+impl HostContract {
+    pub fn init(env: Env, admin: Address) {
+        Access::init(&env, &admin);
+    }
+
+    pub fn grant(env: Env, admin: Address, role: Symbol, account: Address) -> Result<(), AccessError> {
+        Access::require_role(&env, ADMIN, &admin)?;
+        Access::set_role(&env, role, &account);
+        Ok(())
+    }
+
+    pub fn revoke(env: Env, admin: Address, role: Symbol, account: Address) -> Result<(), AccessError> {
+        Access::require_role(&env, ADMIN, &admin)?;
+        Access::revoke_role(&env, role, &account);
+        Ok(())
+    }
+
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        Access::has_role(&env, role, &account)
+    }
+
+    pub fn mint(env: Env, minter: Address) -> Result<(), AccessError> {
+        Access::require_role(&env, MINTER, &minter)
+    }
+}
+
+#[test]
+fn grant_check_and_revoke_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, HostContract);
+    let client = HostContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+
+    client.init(&admin);
+    assert!(client.has_role(&ADMIN, &admin));
+    assert!(!client.has_role(&MINTER, &minter));
+    assert_eq!(client.try_mint(&minter), Err(Ok(AccessError::Unauthorized)));
+
+    client.grant(&admin, &MINTER, &minter);
+    assert!(client.has_role(&MINTER, &minter));
+    client.mint(&minter);
+
+    // Roles are independent: a minter cannot grant roles
+    let result = client.try_grant(&minter, &MINTER, &minter);
+    assert_eq!(result, Err(Ok(AccessError::Unauthorized)));
+
+    client.revoke(&admin, &MINTER, &minter);
+    assert!(!client.has_role(&MINTER, &minter));
+    assert_eq!(client.try_mint(&minter), Err(Ok(AccessError::Unauthorized)));
+}
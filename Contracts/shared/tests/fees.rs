@@ -0,0 +1,99 @@
+use shared::fees::{FeeError, FeeManager};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, vec, Address, Env};
+
+#[contract]
+struct HostContract;
+
+#[contractimpl]
+impl HostContract {
+    pub fn set_fee_bounds(env: Env, min_fee: i128, max_fee: i128) -> Result<(), FeeError> {
+        FeeManager::set_fee_bounds(&env, min_fee, max_fee)
+    }
+
+    pub fn charge(
+        env: Env,
+        token: Address,
+        from: Address,
+        recipient: Address,
+        notional: i128,
+        fee_bps: u32,
+    ) -> Result<i128, FeeError> {
+        from.require_auth();
+        FeeManager::collect_percentage_fee(&env, &token, &from, &recipient, notional, fee_bps)
+    }
+}
+
+fn setup(env: &Env) -> (HostContractClient<'_>, Address, token::Client<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, HostContract);
+    let client = HostContractClient::new(env, &contract_id);
+
+    let token_id = env.register_stellar_asset_contract(Address::generate(env));
+    let trader = Address::generate(env);
+    let recipient = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_id).mint(&trader, &1_000_000);
+
+    (client, token_id.clone(), token::Client::new(env, &token_id), trader, recipient)
+}
+
+#[test]
+fn percentage_fee_charges_bps_of_notional() {
+    let env = Env::default();
+    let (client, token_id, token_client, trader, recipient) = setup(&env);
+
+    // 30 bps of 100_000
+    assert_eq!(client.charge(&token_id, &trader, &recipient, &100_000, &30), 300);
+    assert_eq!(token_client.balance(&recipient), 300);
+
+    assert_eq!(
+        client.try_charge(&token_id, &trader, &recipient, &100_000, &10_001),
+        Err(Ok(FeeError::InvalidFeeRate))
+    );
+}
+
+#[test]
+fn percentage_fee_applies_min_and_max_bounds() {
+    let env = Env::default();
+    let (client, token_id, token_client, trader, recipient) = setup(&env);
+
+    assert_eq!(
+        client.try_set_fee_bounds(&50, &10),
+        Err(Ok(FeeError::InvalidAmount))
+    );
+    client.set_fee_bounds(&10, &1_000);
+
+    // 30 bps of 100 rounds to 0, so the minimum fee applies
+    assert_eq!(client.charge(&token_id, &trader, &recipient, &100, &30), 10);
+
+    // 30 bps of 900_000 is 2_700, capped at the maximum fee
+    assert_eq!(client.charge(&token_id, &trader, &recipient, &900_000, &30), 1_000);
+
+    assert_eq!(token_client.balance(&recipient), 1_010);
+}
+
+#[test]
+fn discount_rejects_fees_that_overflow() {
+    assert_eq!(FeeManager::apply_discount(1_000, 2_500), Ok(750));
+    assert_eq!(
+        FeeManager::apply_discount(i128::MAX / 2, 2_500),
+        Err(FeeError::InvalidAmount)
+    );
+
+    // A full discount multiplies by zero, so any fee is accepted
+    assert_eq!(FeeManager::apply_discount(i128::MAX, 10_000), Ok(0));
+}
+
+#[test]
+fn split_rejects_fees_that_overflow() {
+    let env = Env::default();
+    let split_bps = vec![&env, 6_000u32, 4_000u32];
+
+    let shares = FeeManager::split_fee(&env, 1_001, &split_bps).unwrap();
+    assert_eq!(shares, vec![&env, 601i128, 400i128]);
+
+    assert_eq!(
+        FeeManager::split_fee(&env, i128::MAX / 2, &split_bps),
+        Err(FeeError::InvalidAmount)
+    );
+}
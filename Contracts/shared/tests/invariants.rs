@@ -42,8 +42,9 @@ proptest! {
             &"Stellara Token".into_val(&env),
             &"STLR".into_val(&env),
             &7,
+            &false,
         );
-        token.mint(&user1, &initial_supply);
+        token.mint(&owner, &user1, &initial_supply);
 
         let mut expected_supply = initial_supply;
 
@@ -57,7 +58,7 @@ proptest! {
                     }
                 }
                 Action::Mint(amount) => {
-                    token.mint(&user1, &amount);
+                    token.mint(&owner, &user1, &amount);
                     expected_supply += amount;
                 }
             }
@@ -97,8 +98,9 @@ proptest! {
             &"Stellara Token".into_val(&env),
             &"STLR".into_val(&env),
             &7,
+            &false,
         );
-        token.mint(&user1, &initial_supply);
+        token.mint(&admin, &user1, &initial_supply);
 
         let supply_before = token.total_supply();
 
@@ -132,8 +134,9 @@ proptest! {
             &"Stellara Token".into_val(&env),
             &"STLR".into_val(&env),
             &7,
+            &false,
         );
-        token.mint(&user1, &supply);
+        token.mint(&admin, &user1, &supply);
 
         let amount = transfer_amount.min(supply);
         token.transfer(&user1, &user2, &amount);
@@ -164,10 +167,11 @@ proptest! {
             &"Stellara Token".into_val(&env),
             &"STLR".into_val(&env),
             &7,
+            &false,
         );
 
         let before = token.total_supply();
-        token.mint(&user, &mint_amount);
+        token.mint(&owner, &user, &mint_amount);
         let after = token.total_supply();
         prop_assert_eq!(after, before + mint_amount);
     }
@@ -0,0 +1,40 @@
+use shared::pausable::{Pausable, PausableError};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+#[contract]
+struct HostContract;
+
+#[contractimpl]
+impl HostContract {
+    pub fn set_paused(env: Env, admin: Address, paused: bool) {
+        Pausable::set_paused(&env, &admin, paused);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        Pausable::is_paused(&env)
+    }
+
+    pub fn guarded(env: Env) -> Result<(), PausableError> {
+        Pausable::require_not_paused(&env)
+    }
+}
+
+#[test]
+fn require_not_paused_fails_only_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, HostContract);
+    let client = HostContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    assert!(!client.is_paused());
+    client.guarded();
+
+    client.set_paused(&admin, &true);
+    assert!(client.is_paused());
+    assert_eq!(client.try_guarded(), Err(Ok(PausableError::Paused)));
+
+    client.set_paused(&admin, &false);
+    assert_eq!(client.try_guarded(), Ok(Ok(())));
+}
@@ -0,0 +1,57 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use shared::safe_call::{errors, safe_invoke_retry};
+use soroban_sdk::{Env, Error, Symbol, TryFromVal, Vec};
+
+// Counts attempts outside contract storage, which is rolled back on failure.
+static FLAKY_CALLS: AtomicU32 = AtomicU32::new(0);
+
+mod flaky {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    pub struct FlakyContract;
+
+    #[contractimpl]
+    impl FlakyContract {
+        /// Fails on the first call and succeeds on every call after.
+        pub fn ping(_env: Env) -> Result<u32, Error> {
+            let attempt = FLAKY_CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+            if attempt == 1 {
+                return Err(Error::from_contract_error(1));
+            }
+            Ok(attempt)
+        }
+    }
+}
+
+mod failing {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    pub struct FailingContract;
+
+    #[contractimpl]
+    impl FailingContract {
+        pub fn ping(_env: Env) -> Result<u32, Error> {
+            Err(Error::from_contract_error(1))
+        }
+    }
+}
+
+#[test]
+fn retry_succeeds_on_second_attempt_and_gives_up_after_max_attempts() {
+    let env = Env::default();
+    let func = Symbol::new(&env, "ping");
+
+    let flaky = env.register_contract(None, flaky::FlakyContract);
+    let result = safe_invoke_retry(&env, &flaky, &func, Vec::new(&env), 3).unwrap();
+    assert_eq!(u32::try_from_val(&env, &result).unwrap(), 2);
+    assert_eq!(FLAKY_CALLS.load(Ordering::SeqCst), 2);
+
+    let failing = env.register_contract(None, failing::FailingContract);
+    let result = safe_invoke_retry(&env, &failing, &func, Vec::new(&env), 3);
+    assert_eq!(result.err(), Some(errors::CALL_FAILED));
+}